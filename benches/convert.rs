@@ -0,0 +1,85 @@
+//! Compares `to_case` with converting into a reused buffer.
+//!
+//! ```text
+//! cargo bench --bench convert
+//! ```
+
+use std::fmt::Write;
+
+use convert_case::{Case, Casing};
+use convert_case_extras::{convert_into, CaseWriter};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+/// Field names like the ones found in structured logs.
+const KEYS: &[&str] = &[
+    "requestId",
+    "HTTPStatusCode",
+    "user_agent_string",
+    "X-Forwarded-For",
+    "responseTimeMs",
+    "trace.span.parent_id",
+    "db_query_duration_us",
+    "isRetryAttempt2",
+];
+
+fn cases(c: &mut Criterion) {
+    let mut group = c.benchmark_group("convert");
+    for (name, case) in [
+        ("snake", Case::Snake),
+        ("camel", Case::Camel),
+        ("title", Case::Title),
+    ] {
+        group.bench_with_input(BenchmarkId::new("to_case", name), &case, |b, &case| {
+            b.iter(|| {
+                for key in KEYS {
+                    black_box(black_box(key).to_case(case));
+                }
+            })
+        });
+
+        let mut out = String::new();
+        group.bench_with_input(BenchmarkId::new("convert_into", name), &case, |b, &case| {
+            b.iter(|| {
+                for key in KEYS {
+                    out.clear();
+                    convert_into(black_box(key), case, &mut out).unwrap();
+                    black_box(&out);
+                }
+            })
+        });
+    }
+    group.finish();
+}
+
+fn log_lines(c: &mut Criterion) {
+    let mut group = c.benchmark_group("log_lines");
+
+    group.bench_function("to_case", |b| {
+        let mut out = String::new();
+        b.iter(|| {
+            out.clear();
+            for key in KEYS {
+                out.push_str(&black_box(key).to_case(Case::Snake));
+                out.push('\n');
+            }
+            black_box(&out);
+        })
+    });
+
+    group.bench_function("case_writer", |b| {
+        let mut writer = CaseWriter::new(String::new(), Case::Snake);
+        b.iter(|| {
+            writer.get_mut().clear();
+            for key in KEYS {
+                writer.convert(black_box(key)).unwrap();
+                writer.get_mut().write_char('\n').unwrap();
+            }
+            black_box(writer.get_ref());
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, cases, log_lines);
+criterion_main!(benches);
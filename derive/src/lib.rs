@@ -0,0 +1,438 @@
+//! Procedural macros for [`convert_case_extras`].
+//!
+//! The strings generated by these macros are converted at compile time, so the
+//! generated code only contains string literals.  [`FromStr`](core::str::FromStr)
+//! implementations return a
+//! [`ParseCaseStrError`](convert_case_extras::ParseCaseStrError), so the
+//! `convert_case_extras` crate must also be a dependency.
+
+use std::collections::BTreeMap;
+
+use convert_case::{Case, Casing};
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::ext::IdentExt;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{parse_macro_input, Data, DeriveInput, Error, Fields, Ident, LitInt, LitStr, Token};
+
+/// Derives `as_str`, [`Display`](core::fmt::Display) and
+/// [`FromStr`](core::str::FromStr) for an enum of unit variants, using the names of the
+/// variants converted into a case.
+///
+/// The `#[case]` attribute is required.  `to` names the case that `as_str` and `Display`
+/// produce, and `from` optionally names other cases that `FromStr` accepts alongside the
+/// `to` case.  Cases are named as in
+/// [`case::by_name`](convert_case_extras::case::by_name), like `"snake"`, `"kebab"` or
+/// `"title-ap"`.
+/// ```
+/// use convert_case_extras_derive::CaseStr;
+///
+/// #[derive(Debug, PartialEq, CaseStr)]
+/// #[case(to = "kebab", from = ["snake", "camel"])]
+/// enum Color {
+///     DarkRed,
+///     LightBlue,
+/// }
+///
+/// assert_eq!(Color::DarkRed.as_str(), "dark-red");
+/// assert_eq!(Color::LightBlue.to_string(), "light-blue");
+/// assert_eq!("dark-red".parse(), Ok(Color::DarkRed));
+/// assert_eq!("light_blue".parse(), Ok(Color::LightBlue));
+/// assert_eq!("lightBlue".parse(), Ok(Color::LightBlue));
+///
+/// let err = "DarkRed".parse::<Color>().unwrap_err();
+/// assert_eq!(err.to_string(), "unknown variant `DarkRed`");
+/// ```
+///
+/// A single case can be given to `from` without brackets, and the cases of this crate
+/// can be used as well.
+/// ```
+/// use convert_case_extras_derive::CaseStr;
+///
+/// #[derive(CaseStr)]
+/// #[case(to = "title-chicago", from = "constant")]
+/// enum Book {
+///     AWalkThroughTheForest,
+/// }
+///
+/// assert_eq!(Book::AWalkThroughTheForest.as_str(), "A Walk through the Forest");
+/// assert!("A_WALK_THROUGH_THE_FOREST".parse::<Book>().is_ok());
+/// ```
+///
+/// Random cases can't be computed at compile time.
+/// ```compile_fail
+/// use convert_case_extras_derive::CaseStr;
+///
+/// #[derive(CaseStr)]
+/// #[case(to = "random")]
+/// enum Greeting {
+///     HelloWorld,
+/// }
+/// ```
+///
+/// Two variants that convert to the same string are an error.
+/// ```compile_fail
+/// use convert_case_extras_derive::CaseStr;
+///
+/// #[derive(CaseStr)]
+/// #[case(to = "snake", from = ["flat"])]
+/// enum Mode {
+///     ReadOnly,
+///     Readonly,
+/// }
+/// ```
+#[proc_macro_derive(CaseStr, attributes(case))]
+pub fn derive_case_str(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    case_str(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Converts a string literal into a case at compile time, and expands to the converted
+/// string literal.
+///
+/// The case is named either like a variant of [`Case`](convert_case::Case) or a constant
+/// in [`case`](convert_case_extras::case), optionally with its path, or by a string as in
+/// [`case::by_name`](convert_case_extras::case::by_name).  A path must start with `Case`,
+/// `convert_case::Case`, `case` or `convert_case_extras::case`, and without one, names in
+/// upper snake case are constants and other names are variants.
+/// ```
+/// use convert_case::{Case, Casing};
+/// use convert_case_extras_derive::case;
+///
+/// const TYPE_NAME: &str = case!("my_literal", Pascal);
+/// assert_eq!(TYPE_NAME, "MyLiteral");
+///
+/// assert_eq!(case!("my_literal", Case::UpperKebab), "MY-LITERAL");
+/// assert_eq!(case!("a walk through the forest", case::TITLE_CHICAGO), "A Walk through the Forest");
+/// assert_eq!(case!("myLiteral", DOT), "my.literal");
+/// assert_eq!(case!("myLiteral", "toggle"), "mY lITERAL");
+///
+/// let alternating = "my_literal".to_case(Case::Alternating);
+/// assert_eq!(case!("my_literal", Case::Alternating), alternating);
+/// assert_eq!(case!("my_literal", convert_case::Case::Alternating), alternating);
+/// ```
+///
+/// Other paths are an error, as are names that aren't in the `Case` enum or the `case`
+/// module.
+/// ```compile_fail
+/// use convert_case_extras_derive::case;
+///
+/// const NAME: &str = case!("my_literal", heck::Case::Snake);
+/// ```
+/// ```compile_fail
+/// use convert_case_extras_derive::case;
+///
+/// const NAME: &str = case!("my_literal", Case::Dot);
+/// ```
+/// ```compile_fail
+/// use convert_case_extras_derive::case;
+///
+/// const NAME: &str = case!("my_literal", case::Pascal);
+/// ```
+///
+/// The random cases are only allowed with a seed, and produce the same output as
+/// [`RandomCase`](convert_case_extras::case::RandomCase) with that seed.
+/// ```
+/// use convert_case_extras_derive::case;
+///
+/// const NOISE: &str = case!("my_literal", RANDOM, seed = 42);
+/// assert_eq!(NOISE.to_lowercase(), "my literal");
+/// assert_eq!(NOISE, case!("my_literal", Random, seed = 42));
+/// ```
+/// ```compile_fail
+/// use convert_case_extras_derive::case;
+///
+/// const NOISE: &str = case!("my_literal", PSEUDO_RANDOM);
+/// ```
+#[proc_macro]
+pub fn case(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as CaseInput);
+    convert_literal(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// The arguments of [`case!`](case()).
+struct CaseInput {
+    literal: LitStr,
+    name: CaseName,
+    // What to highlight in errors
+    name_span: proc_macro2::Span,
+    seed: Option<LitInt>,
+}
+
+/// How the case of [`case!`](case()) is named, with the name in kebab case.
+enum CaseName {
+    /// A variant of `convert_case::Case`, and the variant as written.
+    Variant(String, String),
+    /// A constant in `convert_case_extras::case`, and the constant as written.
+    Constant(String, String),
+    /// A string as in `case::by_name`.
+    Str(String),
+}
+
+impl CaseName {
+    fn kebab(&self) -> &str {
+        match self {
+            CaseName::Variant(name, _) | CaseName::Constant(name, _) | CaseName::Str(name) => name,
+        }
+    }
+
+    fn from_path(path: &syn::Path) -> syn::Result<Self> {
+        let prefix: Vec<String> = path
+            .segments
+            .iter()
+            .take(path.segments.len() - 1)
+            .map(|segment| segment.ident.unraw().to_string())
+            .collect();
+        let last = path.segments.last().unwrap().ident.unraw().to_string();
+        let kebab = last.to_case(Case::Kebab);
+
+        let is_constant = match prefix.iter().map(String::as_str).collect::<Vec<_>>()[..] {
+            [] => !last.contains(char::is_lowercase),
+            ["Case"] | ["convert_case", "Case"] => false,
+            ["case"] | ["convert_case_extras", "case"] => true,
+            _ => {
+                return Err(Error::new_spanned(
+                    path,
+                    "expected a variant of `convert_case::Case` or a constant in \
+                     `convert_case_extras::case`",
+                ))
+            }
+        };
+        Ok(if is_constant {
+            CaseName::Constant(kebab, last)
+        } else {
+            CaseName::Variant(kebab, last)
+        })
+    }
+
+    /// Finds the case, which is never random.
+    fn resolve(&self) -> Result<Case<'static>, String> {
+        use convert_case_extras::case::by_name;
+
+        // The cases of this crate are all custom, and `by_name` gives the variants of
+        // `Case` for every other name
+        let is_variant = |case: &Case| !matches!(case, Case::Custom { .. });
+        match self {
+            CaseName::Variant(name, _) if name == "toggle" => Ok(Case::Toggle),
+            CaseName::Variant(name, _) if name == "alternating" => Ok(Case::Alternating),
+            CaseName::Variant(name, written) => by_name(name)
+                .filter(is_variant)
+                .ok_or_else(|| format!("no variant `{}` in `Case`", written)),
+            CaseName::Constant(name, written) => by_name(name)
+                .filter(|case| !is_variant(case))
+                .ok_or_else(|| format!("no constant `{}` in `case`", written)),
+            CaseName::Str(name) => by_name(name).ok_or_else(|| format!("unknown case `{}`", name)),
+        }
+    }
+}
+
+impl Parse for CaseInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let literal = input.parse()?;
+        input.parse::<Token![,]>()?;
+
+        let (name, name_span) = if input.peek(LitStr) {
+            let name: LitStr = input.parse()?;
+            (CaseName::Str(name.value()), name.span())
+        } else {
+            let path: syn::Path = input.parse()?;
+            let span = path.segments.last().unwrap().ident.span();
+            (CaseName::from_path(&path)?, span)
+        };
+
+        let mut seed = None;
+        if input.parse::<Option<Token![,]>>()?.is_some() && !input.is_empty() {
+            let key: Ident = input.parse()?;
+            if key != "seed" {
+                return Err(Error::new(key.span(), "expected `seed = ...`"));
+            }
+            input.parse::<Token![=]>()?;
+            seed = Some(input.parse()?);
+            input.parse::<Option<Token![,]>>()?;
+        }
+
+        Ok(CaseInput {
+            literal,
+            name,
+            name_span,
+            seed,
+        })
+    }
+}
+
+fn convert_literal(input: CaseInput) -> syn::Result<proc_macro2::TokenStream> {
+    use convert_case_extras::case::RandomCase;
+
+    let value = input.literal.value();
+    let converted = match (input.name.kebab(), &input.seed) {
+        ("random", Some(seed)) => RandomCase::random(seed.base10_parse()?).convert(&value),
+        ("pseudo-random", Some(seed)) => {
+            RandomCase::pseudo_random(seed.base10_parse()?).convert(&value)
+        }
+        ("random" | "pseudo-random", None) => {
+            return Err(Error::new(
+                input.name_span,
+                "random cases need a seed to be converted at compile time, \
+                 like `seed = 42`",
+            ))
+        }
+        (_, None) => match input.name.resolve() {
+            Ok(case) => value.to_case(case),
+            Err(message) => return Err(Error::new(input.name_span, message)),
+        },
+        (_, Some(seed)) => return Err(Error::new(seed.span(), "only random cases can be seeded")),
+    };
+
+    let literal = LitStr::new(&converted, input.literal.span());
+    Ok(quote!(#literal))
+}
+
+/// The cases named in a `#[case(to = ..., from = ...)]` attribute.
+struct CaseAttr {
+    to: Case<'static>,
+    from: Vec<Case<'static>>,
+}
+
+fn parse_case(name: &LitStr) -> syn::Result<Case<'static>> {
+    let value = name.value();
+    convert_case_extras::case::by_name(&value).ok_or_else(|| match value.as_str() {
+        "random" | "pseudo-random" => Error::new(
+            name.span(),
+            "random cases can't be converted at compile time",
+        ),
+        _ => Error::new(name.span(), format!("unknown case `{}`", value)),
+    })
+}
+
+fn parse_case_attr(input: &DeriveInput) -> syn::Result<CaseAttr> {
+    let attr = input
+        .attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("case"))
+        .ok_or_else(|| {
+            Error::new_spanned(
+                &input.ident,
+                "missing `#[case(to = \"...\")]` attribute for `CaseStr`",
+            )
+        })?;
+
+    let mut to = None;
+    let mut from = Vec::new();
+    attr.parse_nested_meta(|meta| {
+        if meta.path.is_ident("to") {
+            to = Some(parse_case(&meta.value()?.parse()?)?);
+            Ok(())
+        } else if meta.path.is_ident("from") {
+            let value = meta.value()?;
+            if value.peek(syn::token::Bracket) {
+                let content;
+                syn::bracketed!(content in value);
+                let names: Punctuated<LitStr, Token![,]> =
+                    content.parse_terminated(|input| input.parse(), Token![,])?;
+                for name in &names {
+                    from.push(parse_case(name)?);
+                }
+            } else {
+                from.push(parse_case(&value.parse()?)?);
+            }
+            Ok(())
+        } else {
+            Err(meta.error("expected `to` or `from`"))
+        }
+    })?;
+
+    let to = to.ok_or_else(|| Error::new_spanned(attr, "missing `to = \"...\"`"))?;
+    Ok(CaseAttr { to, from })
+}
+
+fn case_str(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let data = match &input.data {
+        Data::Enum(data) => data,
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "`CaseStr` can only be derived for enums",
+            ))
+        }
+    };
+    let attr = parse_case_attr(&input)?;
+
+    let mut variants: Vec<&Ident> = Vec::new();
+    let mut names: Vec<String> = Vec::new();
+    let mut accepted: Vec<Vec<String>> = Vec::new();
+    // Every accepted string, and the variant it parses to
+    let mut parses_to: BTreeMap<String, &Ident> = BTreeMap::new();
+
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(Error::new_spanned(
+                variant,
+                "`CaseStr` only supports unit variants",
+            ));
+        }
+        let ident = &variant.ident;
+        let unraw = ident.unraw().to_string();
+
+        let mut strs: Vec<String> = Vec::new();
+        for case in std::iter::once(attr.to).chain(attr.from.iter().copied()) {
+            let s = unraw.to_case(case);
+            if !strs.contains(&s) {
+                strs.push(s);
+            }
+        }
+        for s in &strs {
+            if let Some(other) = parses_to.insert(s.clone(), ident) {
+                return Err(Error::new_spanned(
+                    ident,
+                    format!(
+                        "variants `{}` and `{}` both convert to `{}`",
+                        other, ident, s
+                    ),
+                ));
+            }
+        }
+
+        variants.push(ident);
+        names.push(strs[0].clone());
+        accepted.push(strs);
+    }
+
+    let ty = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let accepted = accepted.iter().map(|strs| quote!(#(#strs)|*));
+
+    Ok(quote! {
+        impl #impl_generics #ty #ty_generics #where_clause {
+            /// The name of this variant.
+            pub const fn as_str(&self) -> &'static str {
+                match *self {
+                    #(Self::#variants => #names,)*
+                }
+            }
+        }
+
+        impl #impl_generics ::core::fmt::Display for #ty #ty_generics #where_clause {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.pad(self.as_str())
+            }
+        }
+
+        impl #impl_generics ::core::str::FromStr for #ty #ty_generics #where_clause {
+            type Err = ::convert_case_extras::ParseCaseStrError;
+
+            fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                match s {
+                    #(#accepted => ::core::result::Result::Ok(Self::#variants),)*
+                    _ => ::core::result::Result::Err(
+                        ::convert_case_extras::ParseCaseStrError::new(s),
+                    ),
+                }
+            }
+        }
+    })
+}
@@ -0,0 +1,212 @@
+//! A dictionary of acronyms, initialisms and brand names with fixed casing.
+//!
+//! ```
+//! use convert_case_extras::acronym::Acronyms;
+//!
+//! let acronyms = Acronyms::new().with("Kubernetes");
+//! assert_eq!(acronyms.get("http"), Some("HTTP"));
+//! assert_eq!(acronyms.get("Ios"), Some("iOS"));
+//! assert_eq!(acronyms.get("KUBERNETES"), Some("Kubernetes"));
+//! assert_eq!(acronyms.get("server"), None);
+//! ```
+
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::{String, ToString};
+
+// Passes the initialisms to `$callback`, so that the list and the dictionary string in
+// `boundary::INITIALISMS` are written out from the same words.
+macro_rules! with_initialisms {
+    ($callback:ident) => {
+        $callback!(
+            "ACL", "API", "ASCII", "CPU", "CSS", "DNS", "EOF", "GUID", "HTML", "HTTP", "HTTPS",
+            "ID", "IP", "JSON", "LHS", "QPS", "RAM", "RHS", "RPC", "SLA", "SMTP", "SQL", "SSH",
+            "TCP", "TLS", "TTL", "UDP", "UI", "UID", "UUID", "URI", "URL", "UTF8", "VM", "XML",
+            "XMPP", "XSRF", "XSS"
+        )
+    };
+}
+pub(crate) use with_initialisms;
+
+macro_rules! word_list {
+    ($($word:literal),*) => {
+        &[$($word),*]
+    };
+}
+
+/// The initialisms recognized by Go's `lint` tool.
+pub const INITIALISMS: &[&str] = with_initialisms!(word_list);
+
+/// Brand names and technologies whose casing is not simply capitalized.
+pub const BRANDS: &[&str] = &[
+    "iOS",
+    "iPadOS",
+    "macOS",
+    "tvOS",
+    "watchOS",
+    "iPhone",
+    "iPad",
+    "GitHub",
+    "GitLab",
+    "JavaScript",
+    "TypeScript",
+    "OAuth",
+    "GraphQL",
+    "PostgreSQL",
+    "MySQL",
+    "YouTube",
+    "LinkedIn",
+    "WebSocket",
+    "WebAssembly",
+];
+
+/// A case-insensitive dictionary of words that must keep a particular casing.
+///
+/// Each entry is stored in its canonical form, like `"HTTP"` or `"iOS"`, and is looked
+/// up regardless of how the word is cased in the input.  An entry that is entirely
+/// uppercase is an *initialism*: in camel case it is lowercased as a whole when it is
+/// the first word, and elsewhere it is written fully uppercase.  Any other entry is a
+/// *brand* and is always written verbatim.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Acronyms {
+    // Lowercased word to canonical word
+    words: BTreeMap<String, String>,
+}
+
+impl Default for Acronyms {
+    fn default() -> Self {
+        let mut acronyms = Acronyms::empty();
+        for word in INITIALISMS.iter().chain(BRANDS) {
+            acronyms.insert(word);
+        }
+        acronyms
+    }
+}
+
+impl Acronyms {
+    /// Creates a dictionary with the built-in [`INITIALISMS`] and [`BRANDS`].  This is the
+    /// same as `Default::default()`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a dictionary with no entries.
+    pub fn empty() -> Self {
+        Acronyms {
+            words: BTreeMap::new(),
+        }
+    }
+
+    /// Adds a word in its canonical casing, replacing any entry that differs only by case.
+    pub fn with(mut self, word: &str) -> Self {
+        self.insert(word);
+        self
+    }
+
+    /// Adds each word in its canonical casing.
+    pub fn with_all(mut self, words: &[&str]) -> Self {
+        for word in words {
+            self.insert(word);
+        }
+        self
+    }
+
+    /// Removes the entry matching `word` regardless of case, if it exists.
+    pub fn without(mut self, word: &str) -> Self {
+        self.words.remove(&word.to_lowercase());
+        self
+    }
+
+    /// Adds a word in its canonical casing, replacing any entry that differs only by case.
+    pub fn insert(&mut self, word: &str) {
+        self.words.insert(word.to_lowercase(), word.to_string());
+    }
+
+    /// Returns the canonical casing of `word`, if it is in the dictionary.
+    pub fn get(&self, word: &str) -> Option<&str> {
+        self.words.get(&word.to_lowercase()).map(String::as_str)
+    }
+
+    /// Returns true if `word` is in the dictionary regardless of case.
+    pub fn contains(&self, word: &str) -> bool {
+        self.get(word).is_some()
+    }
+
+    /// Returns true if `word` is an entirely uppercase entry in the dictionary.
+    pub fn is_initialism(&self, word: &str) -> bool {
+        self.get(word).is_some_and(is_initialism)
+    }
+
+    /// Iterates over the canonical form of every entry.
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        self.words.values().map(String::as_str)
+    }
+
+    /// The canonical casing of `word`, also recognizing initialisms followed by a
+    /// plural `s`, like `IDs` and `URLs`, and words with leading or trailing
+    /// punctuation, like `iOS:` or `(API)`.
+    pub(crate) fn canonical(&self, word: &str) -> Option<String> {
+        if let Some(canonical) = self.canonical_word(word) {
+            return Some(canonical);
+        }
+        let core = word.trim_matches(|c: char| !c.is_alphanumeric());
+        if core.is_empty() || core.len() == word.len() {
+            return None;
+        }
+        let start = word.find(core).unwrap_or(0);
+        self.canonical_word(core).map(|canonical| {
+            format!(
+                "{}{}{}",
+                &word[..start],
+                canonical,
+                &word[start + core.len()..]
+            )
+        })
+    }
+
+    fn canonical_word(&self, word: &str) -> Option<String> {
+        if let Some(canonical) = self.get(word) {
+            return Some(canonical.to_string());
+        }
+        let stem = word.strip_suffix('s').or_else(|| word.strip_suffix('S'))?;
+        self.get(stem)
+            .filter(|canonical| is_initialism(canonical))
+            .map(|canonical| format!("{}s", canonical))
+    }
+}
+
+fn is_initialism(canonical: &str) -> bool {
+    canonical.chars().any(char::is_alphabetic) && canonical == canonical.to_uppercase()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn plural_initialisms() {
+        let acronyms = Acronyms::new();
+        assert_eq!(acronyms.canonical("ids"), Some("IDs".to_string()));
+        assert_eq!(acronyms.canonical("URLS"), Some("URLs".to_string()));
+        assert_eq!(acronyms.canonical("ioss"), None);
+        assert_eq!(acronyms.canonical("s"), None);
+        assert_eq!(acronyms.canonical("ios:"), Some("iOS:".to_string()));
+        assert_eq!(acronyms.canonical("(apis)"), Some("(APIs)".to_string()));
+        assert_eq!(acronyms.canonical("--"), None);
+    }
+
+    #[test]
+    fn initialisms_and_brands() {
+        let acronyms = Acronyms::new();
+        assert!(acronyms.is_initialism("Http"));
+        assert!(!acronyms.is_initialism("ios"));
+        assert!(acronyms.contains("ios"));
+        assert!(acronyms.is_initialism("utf8"));
+    }
+
+    #[test]
+    fn builder() {
+        let acronyms = Acronyms::empty().with_all(&["AWS", "S3"]).without("aws");
+        assert_eq!(acronyms.iter().collect::<Vec<_>>(), vec!["S3"]);
+    }
+}
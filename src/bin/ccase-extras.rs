@@ -0,0 +1,515 @@
+//! Converts the case of strings given as arguments or read from stdin.
+//!
+//! ```text
+//! $ ccase-extras --to title-chicago "a walk through the forest"
+//! A Walk through the Forest
+//! $ printf 'myVar\nmyOtherVar\n' | ccase-extras --to constant
+//! MY_VAR
+//! MY_OTHER_VAR
+//! $ echo '"HTTPServer"' | ccase-extras --to snake --json
+//! "http_server"
+//! $ ccase-extras refactor --from camel --to snake src/main.rs > rename.patch
+//! ```
+
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::{Args, CommandFactory, Parser, Subcommand};
+use convert_case::{Boundary, Case};
+use convert_case_extras::lang::Language;
+use convert_case_extras::refactor::{Renamer, LANGUAGES};
+use convert_case_extras::{boundary, case, pattern::RandomPattern, ExtraConverter};
+
+/// Cases that are only valid for `--to`.
+const RANDOM_CASES: &[&str] = &["random", "pseudo-random"];
+
+const BOUNDARIES: &[(&str, Boundary)] = &[
+    ("underscore", Boundary::Underscore),
+    ("hyphen", Boundary::Hyphen),
+    ("space", Boundary::Space),
+    ("dot", Boundary::from_delim(".")),
+    ("upper-lower", Boundary::UpperLower),
+    ("lower-upper", Boundary::LowerUpper),
+    ("digit-upper", Boundary::DigitUpper),
+    ("upper-digit", Boundary::UpperDigit),
+    ("digit-lower", Boundary::DigitLower),
+    ("lower-digit", Boundary::LowerDigit),
+    ("acronym", Boundary::Acronym),
+];
+
+/// Converts the case of each argument, or of each line of stdin when there are
+/// no arguments.
+#[derive(Debug, Parser)]
+#[command(
+    name = "ccase-extras",
+    version,
+    about,
+    args_conflicts_with_subcommands = true,
+    subcommand_negates_reqs = true
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// The case to convert into.  Run with --list to see every case.
+    #[arg(short, long, value_parser = parse_target, required_unless_present = "list")]
+    to: Option<Target>,
+
+    /// The case of the input, whose boundaries are used to split it into words.
+    #[arg(short, long, value_parser = parse_case, conflicts_with = "boundaries")]
+    from: Option<Case<'static>>,
+
+    /// Comma-separated boundaries used to split the input into words.  `defaults` is
+    /// the default boundaries, and `acronyms` splits known acronyms like HTTPSURL.
+    #[arg(short, long, value_parser = parse_boundaries, value_delimiter = ',')]
+    boundaries: Vec<Boundaries>,
+
+    /// Seeds the random and pseudo-random cases, so the output is reproducible.
+    #[arg(short, long)]
+    seed: Option<u64>,
+
+    /// Input and output records are separated by NUL instead of newlines.
+    #[arg(short = '0', long, conflicts_with = "json")]
+    null: bool,
+
+    /// Each line of input is a JSON string, and each line of output is a JSON string.
+    #[arg(short, long)]
+    json: bool,
+
+    /// Lists the names of every case and boundary.
+    #[arg(short, long, exclusive = true)]
+    list: bool,
+
+    /// Strings to convert.  Stdin is read when there are none.
+    inputs: Vec<String>,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Renames identifiers in source files from one case into another, and prints the
+    /// changes as a unified diff.  The files are not modified.
+    Refactor(RefactorArgs),
+}
+
+#[derive(Debug, Args)]
+struct RefactorArgs {
+    /// The case of the identifiers to rename.
+    #[arg(short, long, value_parser = parse_case)]
+    from: Case<'static>,
+
+    /// The case to rename identifiers into.
+    #[arg(short, long, value_parser = parse_case)]
+    to: Case<'static>,
+
+    /// Comma-separated identifiers to leave as they are.
+    #[arg(short, long, value_delimiter = ',')]
+    allow: Vec<String>,
+
+    /// The language of the files: rust, javascript or python.  By default it is guessed
+    /// from the extension of each file.
+    #[arg(short, long, value_parser = parse_language)]
+    language: Option<Language>,
+
+    /// Source files to rename identifiers in.
+    #[arg(required = true)]
+    files: Vec<PathBuf>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Target {
+    Case(Case<'static>),
+    Random { pseudo: bool },
+}
+
+#[derive(Debug, Clone)]
+struct Boundaries(Vec<Boundary>);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Lines,
+    Null,
+    Json,
+}
+
+fn parse_case(name: &str) -> Result<Case<'static>, String> {
+    case::by_name(name).ok_or_else(|| format!("unknown case `{}`, see --list", name))
+}
+
+fn parse_target(name: &str) -> Result<Target, String> {
+    match name {
+        "random" => Ok(Target::Random { pseudo: false }),
+        "pseudo-random" => Ok(Target::Random { pseudo: true }),
+        _ => parse_case(name).map(Target::Case),
+    }
+}
+
+fn parse_language(name: &str) -> Result<Language, String> {
+    match Language::by_name(name) {
+        Some(language) if LANGUAGES.contains(&language) => Ok(language),
+        Some(_) => Err(format!("can't rename identifiers in `{}`", name)),
+        None => Err(format!("unknown language `{}`", name)),
+    }
+}
+
+fn parse_boundaries(name: &str) -> Result<Boundaries, String> {
+    match name {
+        "defaults" => Ok(Boundaries(Boundary::defaults().to_vec())),
+        "acronyms" => Ok(Boundaries(
+            boundary::acronyms(boundary::INITIALISMS).to_vec(),
+        )),
+        _ => BOUNDARIES
+            .iter()
+            .find(|(n, _)| *n == name)
+            .map(|&(_, boundary)| Boundaries(vec![boundary]))
+            .ok_or_else(|| format!("unknown boundary `{}`, see --list", name)),
+    }
+}
+
+impl Cli {
+    fn mode(&self) -> Mode {
+        if self.null {
+            Mode::Null
+        } else if self.json {
+            Mode::Json
+        } else {
+            Mode::Lines
+        }
+    }
+
+    fn converter(&self) -> Result<ExtraConverter, String> {
+        let mut conv = ExtraConverter::new();
+        if let Some(from) = self.from {
+            conv = conv.from_case(from);
+        }
+        if !self.boundaries.is_empty() {
+            let boundaries: Vec<Boundary> = self
+                .boundaries
+                .iter()
+                .flat_map(|b| b.0.iter().copied())
+                .collect();
+            conv = conv.set_boundaries(&boundaries);
+        }
+
+        let conv = match (self.to, self.seed) {
+            (Some(Target::Case(case)), None) => conv.to_case(case),
+            (Some(Target::Case(_)), Some(_)) => {
+                return Err("--seed can only be used with a random case".to_string())
+            }
+            (Some(Target::Random { pseudo }), Some(seed)) => {
+                let pattern = RandomPattern::with_seed(seed);
+                let pattern = if pseudo { pattern.pseudo() } else { pattern };
+                conv.set_pattern(pattern).set_delim(" ")
+            }
+            (Some(Target::Random { pseudo }), None) => conv.to_case(if pseudo {
+                case::PSEUDO_RANDOM
+            } else {
+                case::RANDOM
+            }),
+            (None, _) => return Err("--to is required".to_string()),
+        };
+        Ok(conv)
+    }
+}
+
+fn write_record<W: Write>(output: &mut W, mode: Mode, s: &str) -> io::Result<()> {
+    match mode {
+        Mode::Lines => writeln!(output, "{}", s),
+        Mode::Null => write!(output, "{}\0", s),
+        Mode::Json => writeln!(output, "{}", serde_json::Value::from(s)),
+    }
+}
+
+fn invalid_data(line: usize, message: impl std::fmt::Display) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("record {}: {}", line, message),
+    )
+}
+
+/// Converts every record of `input` and writes it to `output`.
+fn run<R: BufRead, W: Write>(
+    conv: &ExtraConverter,
+    mode: Mode,
+    input: R,
+    mut output: W,
+) -> io::Result<()> {
+    match mode {
+        Mode::Lines => {
+            for line in input.lines() {
+                write_record(&mut output, mode, &conv.convert(line?))?;
+            }
+        }
+        Mode::Null => {
+            for (i, record) in input.split(b'\0').enumerate() {
+                let record = String::from_utf8(record?).map_err(|e| invalid_data(i + 1, e))?;
+                write_record(&mut output, mode, &conv.convert(record))?;
+            }
+        }
+        Mode::Json => {
+            for (i, line) in input.lines().enumerate() {
+                let line = line?;
+                if line.trim().is_empty() {
+                    continue;
+                }
+                let s: String = serde_json::from_str(&line).map_err(|e| invalid_data(i + 1, e))?;
+                write_record(&mut output, mode, &conv.convert(s))?;
+            }
+        }
+    }
+    output.flush()
+}
+
+/// Prints the diff of renaming identifiers in each file.
+fn refactor<W: Write>(args: &RefactorArgs, mut output: W) -> io::Result<()> {
+    let allowed: Vec<&str> = args.allow.iter().map(String::as_str).collect();
+    for path in &args.files {
+        let language = args
+            .language
+            .or_else(|| {
+                path.extension()
+                    .and_then(|ext| ext.to_str())
+                    .and_then(Language::from_extension)
+                    .filter(|language| LANGUAGES.contains(language))
+            })
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("{}: unknown language, see --language", path.display()),
+                )
+            })?;
+        let source = fs::read_to_string(path)
+            .map_err(|e| io::Error::new(e.kind(), format!("{}: {}", path.display(), e)))?;
+
+        let renamer = Renamer::new(language, args.from, args.to).allow(&allowed);
+        let path = path.to_string_lossy().replace('\\', "/");
+        output.write_all(renamer.diff(&path, &source).as_bytes())?;
+    }
+    output.flush()
+}
+
+fn list<W: Write>(mut output: W) -> io::Result<()> {
+    writeln!(output, "Cases:")?;
+    for name in case::names().chain(RANDOM_CASES.iter().copied()) {
+        writeln!(output, "  {}", name)?;
+    }
+    writeln!(output, "Boundaries:")?;
+    for name in BOUNDARIES
+        .iter()
+        .map(|(name, _)| *name)
+        .chain(["defaults", "acronyms"])
+    {
+        writeln!(output, "  {}", name)?;
+    }
+    output.flush()
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let mut stdout = io::BufWriter::new(io::stdout().lock());
+
+    let result = if let Some(Command::Refactor(args)) = &cli.command {
+        refactor(args, stdout)
+    } else if cli.list {
+        list(stdout)
+    } else {
+        let conv = match cli.converter() {
+            Ok(conv) => conv,
+            Err(message) => Cli::command()
+                .error(clap::error::ErrorKind::ArgumentConflict, message)
+                .exit(),
+        };
+        if cli.inputs.is_empty() {
+            run(&conv, cli.mode(), io::stdin().lock(), stdout)
+        } else {
+            cli.inputs
+                .iter()
+                .try_for_each(|s| write_record(&mut stdout, cli.mode(), &conv.convert(s)))
+                .and_then(|()| stdout.flush())
+        }
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        // The reader of our output went away, like `head`, which is not an error
+        Err(e) if e.kind() == io::ErrorKind::BrokenPipe => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("ccase-extras: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn convert(args: &[&str], input: &str) -> String {
+        let cli = Cli::try_parse_from(["ccase-extras"].iter().chain(args)).unwrap();
+        let mut output = Vec::new();
+        run(
+            &cli.converter().unwrap(),
+            cli.mode(),
+            input.as_bytes(),
+            &mut output,
+        )
+        .unwrap();
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn lines() {
+        assert_eq!(
+            convert(&["--to", "snake"], "myVar\nMyOtherVar\n"),
+            "my_var\nmy_other_var\n"
+        );
+        assert_eq!(
+            convert(&["--to", "title-ap"], "the_lord_of_the_rings"),
+            "The Lord of the Rings\n"
+        );
+    }
+
+    #[cfg(feature = "unicode-segmentation")]
+    #[test]
+    fn grapheme_cases() {
+        assert_eq!(
+            convert(&["--to", "grapheme-alternating"], "e\u{301}cole_ba"),
+            "e\u{301}CoLe Ba\n"
+        );
+    }
+
+    #[test]
+    fn from_and_boundaries() {
+        assert_eq!(
+            convert(&["--from", "snake", "--to", "kebab"], "myVar_name\n"),
+            "myvar-name\n"
+        );
+        assert_eq!(
+            convert(
+                &["-b", "lower-upper,acronyms", "--to", "snake"],
+                "getHTTPSURL\n"
+            ),
+            "get_https_url\n"
+        );
+        assert_eq!(
+            convert(&["-b", "dot", "--to", "pascal"], "my.var\n"),
+            "MyVar\n"
+        );
+    }
+
+    #[test]
+    fn null_delimited() {
+        assert_eq!(
+            convert(&["-0", "--to", "dot"], "my var\0other\nvar\0"),
+            "my.var\0other\nvar\0"
+        );
+    }
+
+    #[test]
+    fn json_lines() {
+        assert_eq!(
+            convert(
+                &["--json", "--to", "constant"],
+                "\"my var\"\n\n\"a \\\"quote\\\"\"\n"
+            ),
+            "\"MY_VAR\"\n\"A_\\\"QUOTE\\\"\"\n"
+        );
+
+        let cli = Cli::try_parse_from(["ccase-extras", "--json", "--to", "snake"]).unwrap();
+        let err = run(
+            &cli.converter().unwrap(),
+            Mode::Json,
+            &b"not json"[..],
+            Vec::new(),
+        );
+        assert_eq!(err.unwrap_err().kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn seeded_random() {
+        let args = ["--to", "pseudo-random", "--seed", "7"];
+        let output = convert(&args, "my_variable_name\n");
+        assert_eq!(output, convert(&args, "my_variable_name\n"));
+        assert_eq!(output.to_lowercase(), "my variable name\n");
+    }
+
+    #[test]
+    fn invalid_arguments() {
+        assert!(Cli::try_parse_from(["ccase-extras", "--to", "shouting"]).is_err());
+        assert!(
+            Cli::try_parse_from(["ccase-extras", "--from", "random", "--to", "snake"]).is_err()
+        );
+        assert!(Cli::try_parse_from(["ccase-extras", "--to", "snake", "-0", "--json"]).is_err());
+
+        let cli = Cli::try_parse_from(["ccase-extras", "--to", "snake", "--seed", "1"]).unwrap();
+        assert!(cli.converter().is_err());
+    }
+
+    #[test]
+    fn refactor_files() {
+        let dir = std::env::temp_dir().join(format!("ccase-extras-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let rust = dir.join("lib.rs");
+        let text = dir.join("notes.txt");
+        fs::write(&rust, "fn getName() {} // getName\n").unwrap();
+        fs::write(&text, "let myVar = 1;\n").unwrap();
+
+        let diff = |args: &[&str]| {
+            let cli = Cli::try_parse_from(["ccase-extras", "refactor"].iter().chain(args)).unwrap();
+            let Some(Command::Refactor(args)) = cli.command else {
+                panic!("expected the refactor subcommand");
+            };
+            let mut output = Vec::new();
+            refactor(&args, &mut output).map(|()| String::from_utf8(output).unwrap())
+        };
+        let rust_path = rust.to_str().unwrap();
+        let text_path = text.to_str().unwrap();
+
+        let output = diff(&["--from", "camel", "--to", "snake", rust_path]).unwrap();
+        assert!(output.ends_with(
+            "@@ -1,1 +1,1 @@\n-fn getName() {} // getName\n+fn get_name() {} // getName\n"
+        ));
+        assert_eq!(
+            diff(&["-f", "camel", "-t", "snake", "-a", "getName", rust_path]).unwrap(),
+            ""
+        );
+        let err = diff(&["-f", "camel", "-t", "snake", text_path]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        let output = diff(&["-f", "camel", "-t", "snake", "-l", "js", text_path]).unwrap();
+        assert!(output.contains("+let my_var = 1;\n"));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn refactor_arguments() {
+        assert!(
+            Cli::try_parse_from(["ccase-extras", "refactor", "-f", "camel", "-t", "snake"])
+                .is_err()
+        );
+        assert!(Cli::try_parse_from(["ccase-extras", "refactor", "-f", "camel", "a.rs"]).is_err());
+        assert!(Cli::try_parse_from([
+            "ccase-extras",
+            "refactor",
+            "-f",
+            "camel",
+            "-t",
+            "snake",
+            "-l",
+            "go",
+            "a.go"
+        ])
+        .is_err());
+
+        // Strings to convert can still be named like the subcommand
+        let cli = Cli::try_parse_from(["ccase-extras", "--to", "snake", "refactor"]).unwrap();
+        assert!(cli.command.is_none());
+        assert_eq!(cli.inputs, ["refactor"]);
+    }
+
+    #[test]
+    fn verify_cli() {
+        Cli::command().debug_assert();
+    }
+}
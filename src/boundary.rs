@@ -0,0 +1,270 @@
+//! Boundaries that split acronyms using a dictionary.
+//!
+//! The default boundaries in `convert_case` split `XMLHttpRequest` correctly, but
+//! cannot tell where one acronym ends and the next begins in `getHTTPSURL`, and split
+//! the plural acronym in `userIDs` into `user`, `I` and `Ds`.  The boundaries in this
+//! module consult a dictionary of acronyms to find those words.
+//!
+//! A dictionary is a `'static` string of uppercase acronyms separated by whitespace.
+//! Since [`Boundary::Custom`] only sees the text after a possible boundary, the
+//! dictionary boundaries replace [`Boundary::Acronym`] rather than adding to it:
+//! use [`defaults_with_acronyms`] in place of [`Boundary::defaults`].
+//!
+//! ```
+//! use convert_case::{Case, Casing};
+//! use convert_case_extras::boundary;
+//!
+//! let boundaries = boundary::defaults_with_acronyms(boundary::INITIALISMS);
+//!
+//! assert_eq!(
+//!     "getHTTPSURL".with_boundaries(&boundaries).to_case(Case::Snake),
+//!     "get_https_url",
+//! );
+//! assert_eq!(
+//!     "userIDs".with_boundaries(&boundaries).to_case(Case::Snake),
+//!     "user_ids",
+//! );
+//! assert_eq!(
+//!     "XMLHttpRequest".with_boundaries(&boundaries).to_case(Case::Snake),
+//!     "xml_http_request",
+//! );
+//! ```
+
+use alloc::vec;
+
+use convert_case::Boundary;
+
+macro_rules! dictionary {
+    ($first:literal $(, $word:literal)*) => {
+        concat!($first $(, " ", $word)*)
+    };
+}
+
+/// The initialisms recognized by Go's `lint` tool, as a dictionary.  This is built from
+/// the same list as [`acronym::INITIALISMS`](crate::acronym::INITIALISMS).
+///
+/// Dictionaries are strings because the condition of a [`Boundary::Custom`] can only
+/// be given a `&'static str`, so they are separate from [`Acronyms`]: words added to an
+/// `Acronyms` aren't seen by these boundaries.  To split on extra acronyms, pass
+/// [`acronyms`] a dictionary that includes them.
+///
+/// [`Acronyms`]: crate::acronym::Acronyms
+pub const INITIALISMS: &str = crate::acronym::with_initialisms!(dictionary);
+
+/// Boundaries that split a run of uppercase letters into the acronyms in `dictionary`,
+/// and that keep a plural acronym like `IDs` together.
+///
+/// The second boundary is a replacement for [`Boundary::Acronym`], which should not be
+/// used alongside these.
+/// ```
+/// use convert_case::{Boundary, Case, Casing};
+/// use convert_case_extras::boundary;
+///
+/// const ACRONYMS: [Boundary; 2] = boundary::acronyms("AWS SQS ID");
+///
+/// let boundaries = [Boundary::LowerUpper, ACRONYMS[0], ACRONYMS[1]];
+/// assert_eq!(
+///     "sendAWSSQSMessageIDs".with_boundaries(&boundaries).to_case(Case::Kebab),
+///     "send-aws-sqs-message-ids",
+/// );
+/// ```
+pub const fn acronyms(dictionary: &'static str) -> [Boundary; 2] {
+    [
+        Boundary::Custom {
+            condition: |s, dictionary| within_acronyms(s, dictionary.unwrap_or("")),
+            arg: Some(dictionary),
+            start: 1,
+            len: 0,
+        },
+        Boundary::Custom {
+            condition: |s, dictionary| acronym_before_word(s, dictionary.unwrap_or("")),
+            arg: Some(dictionary),
+            start: 1,
+            len: 0,
+        },
+    ]
+}
+
+/// The same as [`Boundary::defaults`], except [`Boundary::Acronym`] is replaced by the
+/// boundaries from [`acronyms`].
+///
+/// This can be used with `with_boundaries`, or as the boundaries of a custom case for
+/// use with `from_case`.
+/// ```
+/// use convert_case::{Boundary, Case, Casing, Pattern};
+/// use convert_case_extras::boundary;
+///
+/// const PASCAL: Case = Case::Custom {
+///     boundaries: &boundary::defaults_with_acronyms(boundary::INITIALISMS),
+///     pattern: Pattern::Capital,
+///     delim: "",
+/// };
+///
+/// assert_eq!(
+///     "HTTPSURLForUUIDs".from_case(PASCAL).to_case(Case::Snake),
+///     "https_url_for_uuids",
+/// );
+/// ```
+pub const fn defaults_with_acronyms(dictionary: &'static str) -> [Boundary; 10] {
+    let [within, before] = acronyms(dictionary);
+    [
+        Boundary::Underscore,
+        Boundary::Hyphen,
+        Boundary::Space,
+        Boundary::LowerUpper,
+        Boundary::LowerDigit,
+        Boundary::UpperDigit,
+        Boundary::DigitLower,
+        Boundary::DigitUpper,
+        within,
+        before,
+    ]
+}
+
+fn is_upper(g: &str) -> bool {
+    g.to_uppercase() != g.to_lowercase() && g == g.to_uppercase()
+}
+
+fn is_lower(g: &str) -> bool {
+    g.to_uppercase() != g.to_lowercase() && g == g.to_lowercase()
+}
+
+fn entries(dictionary: &str) -> impl Iterator<Item = &str> {
+    dictionary.split_whitespace()
+}
+
+/// True if `s` followed by the graphemes after it make a plural acronym, like the
+/// `Ds` in `IDs`.  `s` is the last two letters of an uppercase run followed by `s`.
+fn is_plural_acronym(s: &[&str], dictionary: &str) -> bool {
+    let ends_word = s.get(3).is_none_or(|g| !is_lower(g));
+    let tail = [s[0], s[1]].concat();
+    ends_word
+        && s.get(2) == Some(&"s")
+        && entries(dictionary).any(|word| word.ends_with(&tail) || word == s[1])
+}
+
+/// True if `word` can be split entirely into entries of `dictionary`.  The
+/// last entry may be followed by a plural `s`.
+fn segments(word: &str, dictionary: &str) -> bool {
+    let word = word.strip_suffix('s').unwrap_or(word);
+    // reachable[i] is true when word[..i] is a sequence of entries
+    let mut reachable = vec![false; word.len() + 1];
+    reachable[0] = true;
+    for i in 0..word.len() {
+        if !reachable[i] {
+            continue;
+        }
+        for entry in entries(dictionary) {
+            if word[i..].starts_with(entry) {
+                reachable[i + entry.len()] = true;
+            }
+        }
+    }
+    reachable[word.len()]
+}
+
+/// Splits between the first two graphemes of `s` when they are inside a run of
+/// uppercase letters and the remainder of the run is made of dictionary entries.
+///
+/// The last letter of a run followed by a lowercase letter starts the next word,
+/// unless that lowercase letter is the `s` of a plural acronym.
+fn within_acronyms(s: &[&str], dictionary: &str) -> bool {
+    if s.len() < 2 || !is_upper(s[0]) || !is_upper(s[1]) {
+        return false;
+    }
+
+    let mut end = s.iter().position(|g| !is_upper(g)).unwrap_or(s.len());
+    if s.get(end).is_some_and(|g| is_lower(g)) {
+        if is_plural_acronym(&s[end - 2..], dictionary) {
+            end += 1;
+        } else {
+            end -= 1;
+        }
+    }
+    if end <= 1 {
+        return false;
+    }
+
+    let rest = s[1..end].concat();
+    if !segments(&rest, dictionary) {
+        return false;
+    }
+
+    // Don't split an entry that straddles the boundary, like the `UUID` in `UUIDURL`
+    // which would otherwise split as `UU` and `IDURL`.
+    !entries(dictionary).any(|entry| {
+        (1..entry.len())
+            .filter(|&k| entry.is_char_boundary(k))
+            .any(|k| {
+                entry[..k].ends_with(s[0])
+                    && rest.starts_with(&entry[k..])
+                    && segments(&rest[entry.len() - k..], dictionary)
+            })
+    })
+}
+
+/// The same as [`Boundary::Acronym`], except a plural acronym is not split.
+fn acronym_before_word(s: &[&str], dictionary: &str) -> bool {
+    s.len() >= 3
+        && is_upper(s[0])
+        && is_upper(s[1])
+        && is_lower(s[2])
+        && !is_plural_acronym(s, dictionary)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use convert_case::split;
+
+    fn words(s: &str, dictionary: &'static str) -> Vec<String> {
+        split(&s, &defaults_with_acronyms(dictionary))
+            .into_iter()
+            .map(String::from)
+            .collect()
+    }
+
+    #[test]
+    fn same_dictionary_as_acronyms() {
+        let words: Vec<&str> = INITIALISMS.split_whitespace().collect();
+        assert_eq!(words, crate::acronym::INITIALISMS);
+    }
+
+    #[test]
+    fn consecutive_acronyms() {
+        assert_eq!(
+            words("getHTTPSURL", INITIALISMS),
+            vec!["get", "HTTPS", "URL"]
+        );
+        assert_eq!(words("APIURL", INITIALISMS), vec!["API", "URL"]);
+        assert_eq!(words("UUIDURL", INITIALISMS), vec!["UUID", "URL"]);
+        assert_eq!(
+            words("XMLHTTPRequest", INITIALISMS),
+            vec!["XML", "HTTP", "Request"]
+        );
+        assert_eq!(words("HTTPServer", INITIALISMS), vec!["HTTP", "Server"]);
+    }
+
+    #[test]
+    fn plural_acronyms() {
+        assert_eq!(words("userIDs", INITIALISMS), vec!["user", "IDs"]);
+        assert_eq!(words("IDsAndURLs", INITIALISMS), vec!["IDs", "And", "URLs"]);
+        assert_eq!(words("getAPIURLs", INITIALISMS), vec!["get", "API", "URLs"]);
+        assert_eq!(words("IDs_list", INITIALISMS), vec!["IDs", "list"]);
+    }
+
+    #[test]
+    fn unknown_acronyms() {
+        assert_eq!(words("XMLHttpRequest", ""), vec!["XML", "Http", "Request"]);
+        assert_eq!(words("ABCDEF", INITIALISMS), vec!["ABCDEF"]);
+        assert_eq!(words("ABCURL", INITIALISMS), vec!["ABC", "URL"]);
+        assert_eq!(words("userIDs", ""), vec!["user", "I", "Ds"]);
+    }
+
+    #[test]
+    fn uppercase_words() {
+        assert_eq!(words("MY_HTTP_URL", INITIALISMS), vec!["MY", "HTTP", "URL"]);
+        assert_eq!(words("HTTPURL_ID", INITIALISMS), vec!["HTTP", "URL", "ID"]);
+    }
+}
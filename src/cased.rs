@@ -0,0 +1,926 @@
+//! Renaming struct fields and enum variants while serializing and deserializing.
+//!
+//! `#[serde(rename_all = "...")]` only supports a fixed set of cases.  A [`Cased`] value
+//! serializes with every struct field and enum variant converted into any [`Case`],
+//! including the ones in this crate.  A [`CasedDeserializer`] accepts field and variant
+//! names in any casing, so a struct can be read from input in snake case, camel case,
+//! title case, or anything else.
+//!
+//! This is only available with the "serde" feature.
+//! ```
+//! use convert_case::Case;
+//! use convert_case_extras::{case, cased::{self, Cased}};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Book {
+//!     book_title: String,
+//!     genre: Genre,
+//! }
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! enum Genre {
+//!     ScienceFiction,
+//! }
+//!
+//! let book = Book { book_title: "Dune".to_string(), genre: Genre::ScienceFiction };
+//!
+//! let json = serde_json::to_string(&Cased::new(&book, Case::Kebab)).unwrap();
+//! assert_eq!(json, r#"{"book-title":"Dune","genre":"science-fiction"}"#);
+//!
+//! let json = serde_json::to_string(&Cased::new(&book, case::TITLE_AP)).unwrap();
+//! assert_eq!(json, r#"{"Book Title":"Dune","Genre":"Science Fiction"}"#);
+//!
+//! let mut de = serde_json::Deserializer::from_str(r#"{"BookTitle":"Dune","GENRE":"science_fiction"}"#);
+//! assert_eq!(cased::deserialize::<Book, _>(&mut de).unwrap(), book);
+//! ```
+//!
+//! Field and variant names are passed to the underlying serializer as `&'static str`,
+//! like serde's own renaming, so every distinct converted name is allocated once and
+//! kept for the life of the program.  So that this memory stays bounded, only the cases
+//! of `convert_case` and the [deterministic cases](crate::case::deterministic_cases) of
+//! this crate can be serialized into.  Serializing into any other case, such as a random
+//! case or a custom case, is an error.
+//!
+//! Fields of types that serde buffers before deserializing, such as those of
+//! `#[serde(flatten)]` and `#[serde(untagged)]` types, are matched exactly as usual.
+
+use std::boxed::Box;
+use std::collections::BTreeSet;
+use std::fmt;
+use std::string::String;
+use std::sync::{Mutex, PoisonError};
+use std::vec::Vec;
+
+use convert_case::{Case, Casing};
+use serde::de::{self, DeserializeSeed, Deserializer, Visitor};
+use serde::ser::{self, Serialize, Serializer};
+
+/// Deserializes a `T` from `deserializer`, accepting its field and variant names in any
+/// casing.  This can be used with `#[serde(deserialize_with = "...")]`.
+///
+/// ```
+/// use convert_case_extras::cased;
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize)]
+/// struct Settings {
+///     max_retries: u32,
+/// }
+///
+/// #[derive(Deserialize)]
+/// struct Config {
+///     #[serde(deserialize_with = "cased::deserialize")]
+///     settings: Settings,
+/// }
+///
+/// let config: Config = serde_json::from_str(r#"{"settings":{"maxRetries":3}}"#).unwrap();
+/// assert_eq!(config.settings.max_retries, 3);
+/// ```
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: de::Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    T::deserialize(CasedDeserializer::new(deserializer))
+}
+
+/// Every converted name handed out by [`intern`].
+static NAMES: Mutex<BTreeSet<&'static str>> = Mutex::new(BTreeSet::new());
+
+/// Returns a `'static` string equal to `name`, allocating it only the first time.
+fn intern(name: String) -> &'static str {
+    let mut names = NAMES.lock().unwrap_or_else(PoisonError::into_inner);
+    if let Some(&interned) = names.get(name.as_str()) {
+        return interned;
+    }
+    let interned: &'static str = Box::leak(name.into_boxed_str());
+    names.insert(interned);
+    interned
+}
+
+/// True if names converted into `case` can be interned.  A fixed set of cases each
+/// produces one name for each field or variant, so only finitely many names are ever
+/// interned.
+fn is_internable(case: Case) -> bool {
+    Case::deterministic_cases().contains(&case)
+        || crate::case::deterministic_cases().contains(&case)
+}
+
+/// The `'static` name that `name` is converted to, or an error if `case` isn't one of
+/// the cases that names can be converted into.
+fn convert<E: ser::Error>(name: &str, case: Case) -> Result<&'static str, E> {
+    if !is_internable(case) {
+        return Err(E::custom(
+            "only the cases of convert_case and the deterministic cases of \
+             convert_case_extras can be used to rename fields and variants",
+        ));
+    }
+    Ok(intern(name.to_case(case)))
+}
+
+/// True if `a` and `b` are the same when ignoring case and anything that isn't a letter
+/// or digit.
+fn loosely_equal(a: &str, b: &str) -> bool {
+    let loose = |s: &str| {
+        s.chars()
+            .filter(|c| c.is_alphanumeric())
+            .flat_map(char::to_lowercase)
+            .collect::<Vec<_>>()
+    };
+    loose(a) == loose(b)
+}
+
+/// The name in `names` that `s` refers to, or `s` itself if there is none.
+fn match_name<'s>(s: &'s str, names: &'static [&'static str]) -> &'s str {
+    if names.contains(&s) {
+        return s;
+    }
+    names
+        .iter()
+        .find(|name| loosely_equal(name, s))
+        .copied()
+        .unwrap_or(s)
+}
+
+/// A value that serializes with its struct fields and enum variants converted into a case.
+///
+/// Map keys and string values are left as they are.  Serializing fails if `case` is
+/// a random or custom case, as described in the [module documentation](self).
+#[derive(Debug, Clone, Copy)]
+pub struct Cased<'a, 'c, T: ?Sized> {
+    value: &'a T,
+    case: Case<'c>,
+}
+
+impl<'a, 'c, T: ?Sized> Cased<'a, 'c, T> {
+    /// Wraps `value` to serialize its names in `case`.
+    pub fn new(value: &'a T, case: Case<'c>) -> Self {
+        Cased { value, case }
+    }
+}
+
+impl<T: Serialize + ?Sized> Serialize for Cased<'_, '_, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.value
+            .serialize(CasedSerializer::new(serializer, self.case))
+    }
+}
+
+/// A serializer that converts every struct field and enum variant into a case before
+/// passing it to the serializer it wraps.
+///
+/// ```
+/// use convert_case::Case;
+/// use convert_case_extras::cased::CasedSerializer;
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct Point {
+///     x_pos: i32,
+///     y_pos: i32,
+/// }
+///
+/// let mut out = Vec::new();
+/// let mut ser = serde_json::Serializer::new(&mut out);
+/// Point { x_pos: 1, y_pos: 2 }
+///     .serialize(CasedSerializer::new(&mut ser, Case::Constant))
+///     .unwrap();
+/// assert_eq!(String::from_utf8(out).unwrap(), r#"{"X_POS":1,"Y_POS":2}"#);
+/// ```
+pub struct CasedSerializer<'c, S> {
+    inner: S,
+    case: Case<'c>,
+}
+
+impl<'c, S> CasedSerializer<'c, S> {
+    /// Wraps `serializer` to convert names into `case`.
+    pub fn new(serializer: S, case: Case<'c>) -> Self {
+        CasedSerializer {
+            inner: serializer,
+            case,
+        }
+    }
+}
+
+/// The serializer of a sequence, tuple, map, struct or variant, whose elements are cased.
+#[doc(hidden)]
+pub struct Compound<'c, C> {
+    inner: C,
+    case: Case<'c>,
+}
+
+impl<'c, C> Compound<'c, C> {
+    fn new(inner: C, case: Case<'c>) -> Self {
+        Compound { inner, case }
+    }
+}
+
+macro_rules! forward_serialize {
+    ($($method:ident($ty:ty)),* $(,)?) => {
+        $(
+            fn $method(self, v: $ty) -> Result<S::Ok, S::Error> {
+                self.inner.$method(v)
+            }
+        )*
+    };
+}
+
+impl<'c, S: Serializer> Serializer for CasedSerializer<'c, S> {
+    type Ok = S::Ok;
+    type Error = S::Error;
+    type SerializeSeq = Compound<'c, S::SerializeSeq>;
+    type SerializeTuple = Compound<'c, S::SerializeTuple>;
+    type SerializeTupleStruct = Compound<'c, S::SerializeTupleStruct>;
+    type SerializeTupleVariant = Compound<'c, S::SerializeTupleVariant>;
+    type SerializeMap = Compound<'c, S::SerializeMap>;
+    type SerializeStruct = Compound<'c, S::SerializeStruct>;
+    type SerializeStructVariant = Compound<'c, S::SerializeStructVariant>;
+
+    forward_serialize! {
+        serialize_bool(bool),
+        serialize_i8(i8),
+        serialize_i16(i16),
+        serialize_i32(i32),
+        serialize_i64(i64),
+        serialize_i128(i128),
+        serialize_u8(u8),
+        serialize_u16(u16),
+        serialize_u32(u32),
+        serialize_u64(u64),
+        serialize_u128(u128),
+        serialize_f32(f32),
+        serialize_f64(f64),
+        serialize_char(char),
+        serialize_str(&str),
+        serialize_bytes(&[u8]),
+        serialize_unit_struct(&'static str),
+    }
+
+    fn serialize_none(self) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_none()
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_some(&Cased::new(value, self.case))
+    }
+
+    fn serialize_unit(self) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_unit()
+    }
+
+    fn serialize_unit_variant(
+        self,
+        name: &'static str,
+        index: u32,
+        variant: &'static str,
+    ) -> Result<S::Ok, S::Error> {
+        self.inner
+            .serialize_unit_variant(name, index, convert(variant, self.case)?)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        name: &'static str,
+        value: &T,
+    ) -> Result<S::Ok, S::Error> {
+        self.inner
+            .serialize_newtype_struct(name, &Cased::new(value, self.case))
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        name: &'static str,
+        index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<S::Ok, S::Error> {
+        self.inner.serialize_newtype_variant(
+            name,
+            index,
+            convert(variant, self.case)?,
+            &Cased::new(value, self.case),
+        )
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, S::Error> {
+        let seq = self.inner.serialize_seq(len)?;
+        Ok(Compound::new(seq, self.case))
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, S::Error> {
+        let tuple = self.inner.serialize_tuple(len)?;
+        Ok(Compound::new(tuple, self.case))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleStruct, S::Error> {
+        let tuple = self.inner.serialize_tuple_struct(name, len)?;
+        Ok(Compound::new(tuple, self.case))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        name: &'static str,
+        index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleVariant, S::Error> {
+        let variant = convert(variant, self.case)?;
+        let tuple = self
+            .inner
+            .serialize_tuple_variant(name, index, variant, len)?;
+        Ok(Compound::new(tuple, self.case))
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap, S::Error> {
+        let map = self.inner.serialize_map(len)?;
+        Ok(Compound::new(map, self.case))
+    }
+
+    fn serialize_struct(
+        self,
+        name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStruct, S::Error> {
+        let fields = self.inner.serialize_struct(name, len)?;
+        Ok(Compound::new(fields, self.case))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        name: &'static str,
+        index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStructVariant, S::Error> {
+        let variant = convert(variant, self.case)?;
+        let fields = self
+            .inner
+            .serialize_struct_variant(name, index, variant, len)?;
+        Ok(Compound::new(fields, self.case))
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.inner.is_human_readable()
+    }
+}
+
+impl<C: ser::SerializeSeq> ser::SerializeSeq for Compound<'_, C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), C::Error> {
+        self.inner.serialize_element(&Cased::new(value, self.case))
+    }
+
+    fn end(self) -> Result<C::Ok, C::Error> {
+        self.inner.end()
+    }
+}
+
+impl<C: ser::SerializeTuple> ser::SerializeTuple for Compound<'_, C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), C::Error> {
+        self.inner.serialize_element(&Cased::new(value, self.case))
+    }
+
+    fn end(self) -> Result<C::Ok, C::Error> {
+        self.inner.end()
+    }
+}
+
+impl<C: ser::SerializeTupleStruct> ser::SerializeTupleStruct for Compound<'_, C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), C::Error> {
+        self.inner.serialize_field(&Cased::new(value, self.case))
+    }
+
+    fn end(self) -> Result<C::Ok, C::Error> {
+        self.inner.end()
+    }
+}
+
+impl<C: ser::SerializeTupleVariant> ser::SerializeTupleVariant for Compound<'_, C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), C::Error> {
+        self.inner.serialize_field(&Cased::new(value, self.case))
+    }
+
+    fn end(self) -> Result<C::Ok, C::Error> {
+        self.inner.end()
+    }
+}
+
+impl<C: ser::SerializeMap> ser::SerializeMap for Compound<'_, C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), C::Error> {
+        self.inner.serialize_key(&Cased::new(key, self.case))
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), C::Error> {
+        self.inner.serialize_value(&Cased::new(value, self.case))
+    }
+
+    fn end(self) -> Result<C::Ok, C::Error> {
+        self.inner.end()
+    }
+}
+
+impl<C: ser::SerializeStruct> ser::SerializeStruct for Compound<'_, C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), C::Error> {
+        self.inner
+            .serialize_field(convert(key, self.case)?, &Cased::new(value, self.case))
+    }
+
+    fn skip_field(&mut self, key: &'static str) -> Result<(), C::Error> {
+        self.inner.skip_field(convert(key, self.case)?)
+    }
+
+    fn end(self) -> Result<C::Ok, C::Error> {
+        self.inner.end()
+    }
+}
+
+impl<C: ser::SerializeStructVariant> ser::SerializeStructVariant for Compound<'_, C> {
+    type Ok = C::Ok;
+    type Error = C::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), C::Error> {
+        self.inner
+            .serialize_field(convert(key, self.case)?, &Cased::new(value, self.case))
+    }
+
+    fn skip_field(&mut self, key: &'static str) -> Result<(), C::Error> {
+        self.inner.skip_field(convert(key, self.case)?)
+    }
+
+    fn end(self) -> Result<C::Ok, C::Error> {
+        self.inner.end()
+    }
+}
+
+/// A deserializer that accepts struct fields and enum variants in any casing.
+///
+/// A name in the input matches a field or variant when they are equal after ignoring
+/// case and any characters that aren't letters or digits, so `userId`, `USER_ID` and
+/// `User Id` all match the field `user_id`.  Names that match exactly are preferred.
+///
+/// ```
+/// use convert_case_extras::cased::CasedDeserializer;
+/// use serde::Deserialize;
+///
+/// #[derive(Debug, PartialEq, Deserialize)]
+/// enum Shape {
+///     UnitCircle,
+///     RightTriangle { base_len: u32 },
+/// }
+///
+/// let mut de = serde_json::Deserializer::from_str(r#"{"right-triangle":{"BASE LEN":3}}"#);
+/// let shape = Shape::deserialize(CasedDeserializer::new(&mut de)).unwrap();
+/// assert_eq!(shape, Shape::RightTriangle { base_len: 3 });
+/// ```
+pub struct CasedDeserializer<D> {
+    inner: D,
+    // The names that identifiers read by this deserializer may refer to
+    names: &'static [&'static str],
+}
+
+impl<D> CasedDeserializer<D> {
+    /// Wraps `deserializer` to accept names in any casing.
+    pub fn new(deserializer: D) -> Self {
+        Self::with_names(deserializer, &[])
+    }
+
+    fn with_names(inner: D, names: &'static [&'static str]) -> Self {
+        CasedDeserializer { inner, names }
+    }
+}
+
+/// Wraps a visitor so that anything it deserializes is also cased.
+#[doc(hidden)]
+pub struct CasedVisitor<V> {
+    inner: V,
+    // Identifiers visited directly, or the keys of a visited map, or the variant of a
+    // visited enum, refer to one of these names.
+    names: &'static [&'static str],
+}
+
+impl<V> CasedVisitor<V> {
+    fn new(inner: V, names: &'static [&'static str]) -> Self {
+        CasedVisitor { inner, names }
+    }
+}
+
+macro_rules! forward_deserialize {
+    ($($method:ident($($arg:ident: $ty:ty),*)),* $(,)?) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, $($arg: $ty,)* visitor: V) -> Result<V::Value, D::Error> {
+                self.inner.$method($($arg,)* CasedVisitor::new(visitor, self.names))
+            }
+        )*
+    };
+}
+
+impl<'de, D: Deserializer<'de>> Deserializer<'de> for CasedDeserializer<D> {
+    type Error = D::Error;
+
+    forward_deserialize! {
+        deserialize_any(),
+        deserialize_bool(),
+        deserialize_i8(),
+        deserialize_i16(),
+        deserialize_i32(),
+        deserialize_i64(),
+        deserialize_i128(),
+        deserialize_u8(),
+        deserialize_u16(),
+        deserialize_u32(),
+        deserialize_u64(),
+        deserialize_u128(),
+        deserialize_f32(),
+        deserialize_f64(),
+        deserialize_char(),
+        deserialize_str(),
+        deserialize_string(),
+        deserialize_bytes(),
+        deserialize_byte_buf(),
+        deserialize_option(),
+        deserialize_unit(),
+        deserialize_unit_struct(name: &'static str),
+        deserialize_newtype_struct(name: &'static str),
+        deserialize_seq(),
+        deserialize_tuple(len: usize),
+        deserialize_tuple_struct(name: &'static str, len: usize),
+        deserialize_map(),
+        deserialize_identifier(),
+        deserialize_ignored_any(),
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, D::Error> {
+        self.inner
+            .deserialize_struct(name, fields, CasedVisitor::new(visitor, fields))
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, D::Error> {
+        self.inner
+            .deserialize_enum(name, variants, CasedVisitor::new(visitor, variants))
+    }
+
+    fn is_human_readable(&self) -> bool {
+        self.inner.is_human_readable()
+    }
+}
+
+impl<'de, S: DeserializeSeed<'de>> DeserializeSeed<'de> for CasedVisitor<S> {
+    type Value = S::Value;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<S::Value, D::Error> {
+        self.inner
+            .deserialize(CasedDeserializer::with_names(deserializer, self.names))
+    }
+}
+
+macro_rules! forward_visit {
+    ($($method:ident($ty:ty)),* $(,)?) => {
+        $(
+            fn $method<E: de::Error>(self, v: $ty) -> Result<V::Value, E> {
+                self.inner.$method(v)
+            }
+        )*
+    };
+}
+
+impl<'de, V: Visitor<'de>> Visitor<'de> for CasedVisitor<V> {
+    type Value = V::Value;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        self.inner.expecting(formatter)
+    }
+
+    forward_visit! {
+        visit_bool(bool),
+        visit_i8(i8),
+        visit_i16(i16),
+        visit_i32(i32),
+        visit_i64(i64),
+        visit_i128(i128),
+        visit_u8(u8),
+        visit_u16(u16),
+        visit_u32(u32),
+        visit_u64(u64),
+        visit_u128(u128),
+        visit_f32(f32),
+        visit_f64(f64),
+        visit_char(char),
+        visit_bytes(&[u8]),
+        visit_borrowed_bytes(&'de [u8]),
+        visit_byte_buf(Vec<u8>),
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<V::Value, E> {
+        self.inner.visit_str(match_name(v, self.names))
+    }
+
+    fn visit_borrowed_str<E: de::Error>(self, v: &'de str) -> Result<V::Value, E> {
+        match match_name(v, self.names) {
+            name if name == v => self.inner.visit_borrowed_str(v),
+            name => self.inner.visit_str(name),
+        }
+    }
+
+    fn visit_string<E: de::Error>(self, v: String) -> Result<V::Value, E> {
+        match match_name(&v, self.names) {
+            name if name == v => self.inner.visit_string(v),
+            name => self.inner.visit_str(name),
+        }
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<V::Value, E> {
+        self.inner.visit_none()
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<V::Value, D::Error> {
+        self.inner.visit_some(CasedDeserializer::new(deserializer))
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<V::Value, E> {
+        self.inner.visit_unit()
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<V::Value, D::Error> {
+        self.inner
+            .visit_newtype_struct(CasedDeserializer::new(deserializer))
+    }
+
+    fn visit_seq<A: de::SeqAccess<'de>>(self, seq: A) -> Result<V::Value, A::Error> {
+        self.inner.visit_seq(CasedVisitor::new(seq, &[]))
+    }
+
+    fn visit_map<A: de::MapAccess<'de>>(self, map: A) -> Result<V::Value, A::Error> {
+        self.inner.visit_map(CasedVisitor::new(map, self.names))
+    }
+
+    fn visit_enum<A: de::EnumAccess<'de>>(self, data: A) -> Result<V::Value, A::Error> {
+        self.inner.visit_enum(CasedVisitor::new(data, self.names))
+    }
+}
+
+impl<'de, A: de::SeqAccess<'de>> de::SeqAccess<'de> for CasedVisitor<A> {
+    type Error = A::Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, A::Error> {
+        self.inner.next_element_seed(CasedVisitor::new(seed, &[]))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.inner.size_hint()
+    }
+}
+
+impl<'de, A: de::MapAccess<'de>> de::MapAccess<'de> for CasedVisitor<A> {
+    type Error = A::Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, A::Error> {
+        self.inner
+            .next_key_seed(CasedVisitor::new(seed, self.names))
+    }
+
+    fn next_value_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> Result<T::Value, A::Error> {
+        self.inner.next_value_seed(CasedVisitor::new(seed, &[]))
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        self.inner.size_hint()
+    }
+}
+
+impl<'de, A: de::EnumAccess<'de>> de::EnumAccess<'de> for CasedVisitor<A> {
+    type Error = A::Error;
+    type Variant = CasedVisitor<A::Variant>;
+
+    fn variant_seed<T: DeserializeSeed<'de>>(
+        self,
+        seed: T,
+    ) -> Result<(T::Value, Self::Variant), A::Error> {
+        let (value, variant) = self
+            .inner
+            .variant_seed(CasedVisitor::new(seed, self.names))?;
+        Ok((value, CasedVisitor::new(variant, &[])))
+    }
+}
+
+impl<'de, A: de::VariantAccess<'de>> de::VariantAccess<'de> for CasedVisitor<A> {
+    type Error = A::Error;
+
+    fn unit_variant(self) -> Result<(), A::Error> {
+        self.inner.unit_variant()
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value, A::Error> {
+        self.inner
+            .newtype_variant_seed(CasedVisitor::new(seed, &[]))
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, len: usize, visitor: V) -> Result<V::Value, A::Error> {
+        self.inner
+            .tuple_variant(len, CasedVisitor::new(visitor, &[]))
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, A::Error> {
+        self.inner
+            .struct_variant(fields, CasedVisitor::new(visitor, fields))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use serde::{Deserialize, Serialize};
+    use std::collections::BTreeMap;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Account {
+        user_name: String,
+        home_page: Option<Link>,
+        linked_accounts: Vec<Provider>,
+        extra_fields: BTreeMap<String, u32>,
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Link(String);
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    enum Provider {
+        GitHub,
+        EmailAddress(String),
+        OAuthToken { token_value: String },
+        KeyPair(u32, u32),
+    }
+
+    fn account() -> Account {
+        Account {
+            user_name: "my_name".to_string(),
+            home_page: Some(Link("my_page".to_string())),
+            linked_accounts: vec![
+                Provider::GitHub,
+                Provider::EmailAddress("my_email".to_string()),
+                Provider::OAuthToken {
+                    token_value: "my_token".to_string(),
+                },
+                Provider::KeyPair(1, 2),
+            ],
+            extra_fields: BTreeMap::from([("field_name".to_string(), 1)]),
+        }
+    }
+
+    #[test]
+    fn serialize_json() {
+        let json = serde_json::to_value(Cased::new(&account(), Case::Camel)).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "userName": "my_name",
+                "homePage": "my_page",
+                "linkedAccounts": [
+                    "gitHub",
+                    { "emailAddress": "my_email" },
+                    { "oAuthToken": { "tokenValue": "my_token" } },
+                    { "keyPair": [1, 2] },
+                ],
+                "extraFields": { "field_name": 1 },
+            })
+        );
+    }
+
+    #[test]
+    fn serialize_crate_cases() {
+        let json = serde_json::to_string(&Cased::new(&Provider::GitHub, crate::case::TOGGLE));
+        assert_eq!(json.unwrap(), r#""gIT hUB""#);
+
+        let json = serde_json::to_string(&Cased::new(&Provider::GitHub, crate::case::DOT));
+        assert_eq!(json.unwrap(), r#""git.hub""#);
+    }
+
+    #[test]
+    fn round_trip() {
+        let cases = [
+            Case::Snake,
+            Case::Kebab,
+            Case::Constant,
+            Case::Pascal,
+            Case::Title,
+            crate::case::TOGGLE,
+            crate::case::ALTERNATING,
+            crate::case::TITLE_CHICAGO,
+            crate::case::DOT,
+        ];
+        for case in cases {
+            let json = serde_json::to_string(&Cased::new(&account(), case)).unwrap();
+            let mut de = serde_json::Deserializer::from_str(&json);
+            assert_eq!(deserialize::<Account, _>(&mut de).unwrap(), account());
+        }
+    }
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn mixed_input_casings() {
+        let yaml = "
+            UserName: my_name
+            home-page: my_page
+            LINKED_ACCOUNTS: [GIT_HUB, !email-address my_email]
+            extraFields: { field_name: 1 }
+        ";
+        let account: Account = deserialize(serde_yaml::Deserializer::from_str(yaml)).unwrap();
+        assert_eq!(account.user_name, "my_name");
+        assert_eq!(account.home_page, Some(Link("my_page".to_string())));
+        assert_eq!(
+            account.linked_accounts,
+            vec![
+                Provider::GitHub,
+                Provider::EmailAddress("my_email".to_string())
+            ]
+        );
+        assert_eq!(account.extra_fields["field_name"], 1);
+    }
+
+    #[test]
+    fn unknown_names() {
+        let mut de = serde_json::Deserializer::from_str(r#""Bitbucket""#);
+        let err = deserialize::<Provider, _>(&mut de).unwrap_err();
+        assert!(err.to_string().starts_with("unknown variant `Bitbucket`"));
+    }
+
+    #[test]
+    fn interned_names() {
+        let convert = |name| convert::<serde_json::Error>(name, Case::Camel).unwrap();
+        assert!(core::ptr::eq(convert("user_name"), convert("user_name")));
+    }
+
+    #[test]
+    fn uninternable_cases() {
+        const SHOUT: Case = Case::Custom {
+            boundaries: &[],
+            pattern: convert_case::Pattern::Uppercase,
+            delim: "!",
+        };
+        let err = serde_json::to_string(&Cased::new(&account(), SHOUT)).unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("only the cases of convert_case"));
+        assert!(serde_json::to_string(&Cased::new(&Provider::GitHub, SHOUT)).is_err());
+    }
+
+    #[cfg(feature = "random")]
+    #[test]
+    fn random_cases_are_rejected() {
+        let result = serde_json::to_string(&Cased::new(&account(), crate::case::RANDOM));
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,188 @@
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use convert_case::{Boundary, Case, Pattern};
+
+use crate::ExtraPattern;
+
+/// The parameters for performing a case conversion with an [`ExtraPattern`].
+///
+/// This mirrors [`convert_case::Converter`], but the pattern can be any
+/// [`ExtraPattern`], including ones that carry configuration or state.  Splitting
+/// on boundaries and joining with the delimeter work exactly as they do in
+/// `convert_case`.
+///
+/// ```
+/// use convert_case::{Boundary, Case, Pattern};
+/// use convert_case_extras::ExtraConverter;
+///
+/// let conv = ExtraConverter::new()
+///     .set_boundaries(&[Boundary::Underscore])
+///     .set_pattern(Pattern::Uppercase)
+///     .set_delim(".");
+/// assert_eq!(conv.convert("my_var_name"), "MY.VAR.NAME");
+///
+/// let conv = ExtraConverter::new().from_case(Case::Snake).to_case(Case::Kebab);
+/// assert_eq!(conv.convert("my_var_name"), "my-var-name");
+/// ```
+pub struct ExtraConverter {
+    /// How a string is split into words.
+    pub boundaries: Vec<Boundary>,
+
+    /// How each word is mutated before joining.
+    pub pattern: Box<dyn ExtraPattern>,
+
+    /// The string used to join mutated words together.
+    pub delim: String,
+}
+
+impl Default for ExtraConverter {
+    fn default() -> Self {
+        ExtraConverter {
+            boundaries: Boundary::defaults().to_vec(),
+            pattern: Box::new(Pattern::Noop),
+            delim: String::new(),
+        }
+    }
+}
+
+impl ExtraConverter {
+    /// Creates a new `ExtraConverter` with default fields.  This is the same as `Default::default()`.
+    /// The `ExtraConverter` will use `Boundary::defaults()` for boundaries, no pattern, and an empty
+    /// string as a delimeter.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Converts a string.
+    pub fn convert<T>(&self, s: T) -> String
+    where
+        T: AsRef<str>,
+    {
+        let words = convert_case::split(&s, &self.boundaries);
+        self.pattern.mutate(&words).join(&self.delim)
+    }
+
+    /// Set the pattern and delimiter to those associated with the given case.
+    pub fn to_case(mut self, case: Case) -> Self {
+        self.pattern = Box::new(case.pattern());
+        self.delim = case.delim().to_string();
+        self
+    }
+
+    /// Sets the boundaries to those associated with the provided case.  This is used
+    /// by the `from_case` function in the `Casing` trait.
+    pub fn from_case(mut self, case: Case) -> Self {
+        self.boundaries = case.boundaries().to_vec();
+        self
+    }
+
+    /// Sets the boundaries to those provided.
+    pub fn set_boundaries(mut self, bs: &[Boundary]) -> Self {
+        self.boundaries = bs.to_vec();
+        self
+    }
+
+    /// Adds a boundary to the list of boundaries.
+    pub fn add_boundary(mut self, b: Boundary) -> Self {
+        self.boundaries.push(b);
+        self
+    }
+
+    /// Adds a vector of boundaries to the list of boundaries.
+    pub fn add_boundaries(mut self, bs: &[Boundary]) -> Self {
+        self.boundaries.extend(bs);
+        self
+    }
+
+    /// Removes a boundary from the list of boundaries if it exists.
+    pub fn remove_boundary(mut self, b: Boundary) -> Self {
+        self.boundaries.retain(|&x| x != b);
+        self
+    }
+
+    /// Removes all the provided boundaries from the list of boundaries if it exists.
+    pub fn remove_boundaries(mut self, bs: &[Boundary]) -> Self {
+        self.boundaries.retain(|x| !bs.contains(x));
+        self
+    }
+
+    /// Sets the delimeter.
+    pub fn set_delim<T>(mut self, d: T) -> Self
+    where
+        T: ToString,
+    {
+        self.delim = d.to_string();
+        self
+    }
+
+    /// Sets the pattern.
+    pub fn set_pattern<P>(mut self, p: P) -> Self
+    where
+        P: ExtraPattern + 'static,
+    {
+        self.pattern = Box::new(p);
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use convert_case::Casing;
+
+    struct Reverse;
+
+    impl ExtraPattern for Reverse {
+        fn mutate(&self, words: &[&str]) -> Vec<String> {
+            words.iter().rev().map(|word| word.to_string()).collect()
+        }
+    }
+
+    struct Numbered {
+        start: usize,
+    }
+
+    impl ExtraPattern for Numbered {
+        fn mutate(&self, words: &[&str]) -> Vec<String> {
+            words
+                .iter()
+                .enumerate()
+                .map(|(i, word)| format!("{}{}", word, self.start + i))
+                .collect()
+        }
+    }
+
+    #[test]
+    fn same_as_convert_case() {
+        let conv = ExtraConverter::new().to_case(Case::Snake);
+        assert_eq!(conv.convert("myVarName"), "myVarName".to_case(Case::Snake));
+    }
+
+    #[test]
+    fn stateless_pattern() {
+        let conv = ExtraConverter::new()
+            .from_case(Case::Kebab)
+            .set_pattern(Reverse)
+            .set_delim("-");
+        assert_eq!(conv.convert("one-two-three"), "three-two-one");
+    }
+
+    #[test]
+    fn configured_pattern() {
+        let conv = ExtraConverter::new()
+            .set_pattern(Numbered { start: 1 })
+            .set_delim("_");
+        assert_eq!(conv.convert("a b c"), "a1_b2_c3");
+    }
+
+    #[test]
+    fn remove_boundaries() {
+        let conv = ExtraConverter::new()
+            .remove_boundaries(&Boundary::digits())
+            .to_case(Case::Snake);
+        assert_eq!(conv.convert("a8aA8A"), "a8a_a8a");
+    }
+}
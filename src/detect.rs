@@ -0,0 +1,178 @@
+use alloc::vec::Vec;
+
+use convert_case::{Case, Casing};
+
+use crate::case;
+
+/// How strongly a string indicates that it is in a particular case.
+///
+/// A string is only ever reported in a case it is consistent with, as determined by
+/// [`Casing::is_case`], or by converting the string from that case into itself.  The
+/// second check is needed for cases like toggle, whose words `is_case` would split on
+/// their lower-upper boundaries.  The confidence then says whether the string also shows the
+/// structure of that case, meaning it is made of more than one word when split by the
+/// boundaries of the case.  `"my_var"` is [`High`](Confidence::High) for snake case, while
+/// `"var"` is [`Low`](Confidence::Low) for snake case, since it has no underscore to prove it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Confidence {
+    /// The string is consistent with the case, but has only a single word.
+    Low,
+    /// The string is consistent with the case and its words are joined like the case joins them.
+    High,
+}
+
+/// Guesses which cases a string is in.
+///
+/// Every case in [`Case::deterministic_cases`] and [`case::deterministic_cases`] that the
+/// string is in is returned, along with a [`Confidence`].  Results are ordered from highest
+/// to lowest confidence, and otherwise in the order the cases are listed.  The toggle and
+/// alternating cases of this crate, including the grapheme ones, are only reported as
+/// [`Case::Toggle`] and [`Case::Alternating`].
+///
+/// A string without any uppercase or lowercase letters is not in any case.
+/// ```
+/// use convert_case::Case;
+/// use convert_case_extras::{case, detect_case, Confidence};
+///
+/// assert_eq!(
+///     detect_case("my_variable_name"),
+///     vec![(Case::Snake, Confidence::High)],
+/// );
+/// assert_eq!(detect_case("myVariableName")[0], (Case::Camel, Confidence::High));
+///
+/// let (dot, confidence) = detect_case("my.variable.name")[0];
+/// assert_eq!(dot.delim(), case::DOT.delim());
+/// assert_eq!(confidence, Confidence::High);
+///
+/// let ambiguous = detect_case("word");
+/// assert!(ambiguous.contains(&(Case::Snake, Confidence::Low)));
+/// assert!(ambiguous.contains(&(Case::Kebab, Confidence::Low)));
+/// assert!(ambiguous.contains(&(Case::Flat, Confidence::Low)));
+/// assert!(ambiguous.contains(&(Case::Camel, Confidence::Low)));
+/// ```
+pub fn detect_case(s: &str) -> Vec<(Case<'static>, Confidence)> {
+    if !s.chars().any(|c| c.is_lowercase() || c.is_uppercase()) {
+        return Vec::new();
+    }
+
+    // Reported as the toggle and alternating cases of convert_case instead
+    let equivalent = [
+        case::TOGGLE,
+        case::ALTERNATING,
+        #[cfg(feature = "unicode-segmentation")]
+        case::GRAPHEME_TOGGLE,
+        #[cfg(feature = "unicode-segmentation")]
+        case::GRAPHEME_ALTERNATING,
+    ];
+    let crate_cases = case::deterministic_cases()
+        .iter()
+        .filter(|c| !equivalent.contains(c));
+
+    let mut found: Vec<(Case<'static>, Confidence)> = Case::deterministic_cases()
+        .iter()
+        .chain(crate_cases)
+        .filter(|&&c| s.is_case(c) || round_trips(s, c))
+        .map(|&c| {
+            if convert_case::split(&s, c.boundaries()).len() > 1 {
+                (c, Confidence::High)
+            } else {
+                (c, Confidence::Low)
+            }
+        })
+        .collect();
+
+    // Stable, so cases with the same confidence keep their order
+    found.sort_by_key(|&(_, confidence)| core::cmp::Reverse(confidence));
+    found
+}
+
+/// True if `s` is unchanged when converted from `case` to `case`, and none of its words
+/// contain a delimiter that the case does not split on.
+fn round_trips(s: &str, case: Case) -> bool {
+    convert_case::split(&s, case.boundaries())
+        .iter()
+        .all(|word| !word.contains([' ', '_', '-']))
+        && s.from_case(case).to_case(case) == s
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn high(s: &str) -> Vec<Case<'static>> {
+        detect_case(s)
+            .into_iter()
+            .filter(|(_, confidence)| *confidence == Confidence::High)
+            .map(|(case, _)| case)
+            .collect()
+    }
+
+    #[test]
+    fn distinct_cases() {
+        assert_eq!(high("my-variable-name"), vec![Case::Kebab]);
+        assert_eq!(high("MyVariableName"), vec![Case::Pascal]);
+        assert_eq!(high("MY_VARIABLE_NAME"), vec![Case::Constant]);
+        assert_eq!(high("MY-VARIABLE-NAME"), vec![Case::Cobol]);
+        assert_eq!(high("My-Variable-Name"), vec![Case::Train]);
+        assert_eq!(high("My_Variable_Name"), vec![Case::Ada]);
+        assert_eq!(high("mY vARIABLE nAME"), vec![Case::Toggle]);
+        assert_eq!(high("mY vArIaBlE nAmE"), vec![Case::Alternating]);
+        assert_eq!(high("my variable name"), vec![Case::Lower]);
+        // Sentence preserving case keeps words that are already uppercase
+        assert_eq!(
+            high("MY VARIABLE NAME"),
+            vec![Case::Upper, case::SENTENCE_PRESERVING]
+        );
+    }
+
+    #[test]
+    fn overlapping_cases() {
+        assert_eq!(
+            high("My variable name"),
+            vec![Case::Sentence, case::SENTENCE_PRESERVING]
+        );
+        assert_eq!(
+            high("Sync to GitHub from NASA"),
+            vec![case::SENTENCE_PRESERVING]
+        );
+
+        // Title case and the four title style guides
+        let star_wars = high("Star Wars");
+        assert_eq!(star_wars.len(), 5);
+        assert_eq!(star_wars[0], Case::Title);
+
+        // Only the four title style guides
+        let rings = high("The Lord of the Rings");
+        assert_eq!(rings.len(), 4);
+        for case in rings {
+            assert_eq!(
+                "the_lord_of_the_rings".to_case(case),
+                "The Lord of the Rings"
+            );
+        }
+    }
+
+    #[test]
+    fn dot_case() {
+        let dot = high("my.variable.name");
+        assert_eq!(dot.len(), 1);
+        assert_eq!(dot[0].delim(), ".");
+    }
+
+    #[test]
+    fn single_word() {
+        let upper: Vec<_> = detect_case("WORD").into_iter().map(|(c, _)| c).collect();
+        assert!(upper.contains(&Case::Constant));
+        assert!(upper.contains(&Case::UpperFlat));
+        assert!(upper.contains(&Case::Upper));
+        assert!(detect_case("WORD")
+            .iter()
+            .all(|(_, confidence)| *confidence == Confidence::Low));
+    }
+
+    #[test]
+    fn no_letters() {
+        assert!(detect_case("").is_empty());
+        assert!(detect_case("123_456").is_empty());
+    }
+}
@@ -0,0 +1,261 @@
+//! Mapping nested configuration keys to the names of environment variables.
+//!
+//! A key like `database.pool.maxSize` is a path of segments separated by dots.  Its
+//! environment variable joins the converted segments with a nesting separator, after a
+//! prefix: `APP_DATABASE__POOL__MAX_SIZE`.
+//!
+//! ```
+//! use convert_case_extras::env::EnvMapper;
+//!
+//! let mapper = EnvMapper::new().prefix("APP");
+//! assert_eq!(mapper.to_var("database.pool.maxSize"), "APP_DATABASE__POOL__MAX_SIZE");
+//! assert_eq!(
+//!     mapper.to_key("APP_DATABASE__POOL__MAX_SIZE").as_deref(),
+//!     Some("database.pool.maxSize"),
+//! );
+//! assert_eq!(mapper.to_key("HOME"), None);
+//! ```
+
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use convert_case::{Case, Casing};
+
+/// Converts between configuration keys and environment variable names.
+///
+/// Segments of keys are split into words on the default boundaries, so `max_size`,
+/// `max-size` and `maxSize` all become `MAX_SIZE`.  Environment variables are split on
+/// the boundaries of their case, so they are mapped back to keys in the key case.
+///
+/// Mapping is round-trippable: a variable is only mapped to a key when that key maps
+/// back to the same variable, and a key in the key case maps to a variable that maps
+/// back to it.  Variables without the prefix, or that aren't written in the variable
+/// case, aren't mapped to any key.
+/// ```
+/// use convert_case::Case;
+/// use convert_case_extras::env::EnvMapper;
+///
+/// let mapper = EnvMapper::new()
+///     .prefix("MY_APP")
+///     .separator("_")
+///     .key_separator("/")
+///     .key_case(Case::Snake);
+/// assert_eq!(mapper.to_var("server/listenPort"), "MY_APP_SERVER_LISTEN_PORT");
+/// assert_eq!(mapper.to_key("MY_APP_SERVER").as_deref(), Some("server"));
+/// assert_eq!(mapper.to_key("MY_APP_server"), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct EnvMapper<'b> {
+    prefix: String,
+    separator: String,
+    key_separator: String,
+    var_case: Case<'b>,
+    key_case: Case<'b>,
+}
+
+impl Default for EnvMapper<'_> {
+    fn default() -> Self {
+        EnvMapper {
+            prefix: String::new(),
+            separator: "__".to_string(),
+            key_separator: ".".to_string(),
+            var_case: Case::Constant,
+            key_case: Case::Camel,
+        }
+    }
+}
+
+impl<'b> EnvMapper<'b> {
+    /// Creates a mapper with no prefix, which maps camel case keys separated by `.` to
+    /// constant case variables separated by `__`.  This is the same as
+    /// `Default::default()`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts every variable with `prefix`, which is joined to the rest of the variable
+    /// with the delimiter of the variable case.
+    pub fn prefix(mut self, prefix: &str) -> Self {
+        self.prefix = prefix.to_string();
+        self
+    }
+
+    /// Separates the segments of keys in variables with `separator`.
+    ///
+    /// When the separator is also the delimiter of the variable case, segments that are
+    /// made of more than one word can't be told apart from nested segments.
+    pub fn separator(mut self, separator: &str) -> Self {
+        self.separator = separator.to_string();
+        self
+    }
+
+    /// Separates the segments of keys with `separator`.
+    pub fn key_separator(mut self, separator: &str) -> Self {
+        self.key_separator = separator.to_string();
+        self
+    }
+
+    /// Writes variables in `case`.
+    pub fn var_case(mut self, case: Case<'b>) -> Self {
+        self.var_case = case;
+        self
+    }
+
+    /// Writes keys in `case`.
+    pub fn key_case(mut self, case: Case<'b>) -> Self {
+        self.key_case = case;
+        self
+    }
+
+    /// The start of every variable, including the delimiter after the prefix.
+    fn var_prefix(&self) -> String {
+        if self.prefix.is_empty() {
+            String::new()
+        } else {
+            format!("{}{}", self.prefix, self.var_case.delim())
+        }
+    }
+
+    /// Returns the name of the variable for `key`.
+    pub fn to_var(&self, key: &str) -> String {
+        let segments: Vec<String> = key
+            .split(self.key_separator.as_str())
+            .map(|segment| segment.to_case(self.var_case))
+            .collect();
+        self.var_prefix() + &segments.join(&self.separator)
+    }
+
+    /// Returns the key for the variable `var`, or `None` if it doesn't have the prefix,
+    /// has an empty segment, or isn't written as this mapper would write it.
+    pub fn to_key(&self, var: &str) -> Option<String> {
+        let rest = var.strip_prefix(&self.var_prefix())?;
+        let mut segments = Vec::new();
+        for segment in rest.split(self.separator.as_str()) {
+            if segment.is_empty() {
+                return None;
+            }
+            segments.push(segment.from_case(self.var_case).to_case(self.key_case));
+        }
+        let key = segments.join(&self.key_separator);
+        (self.to_var(&key) == var).then_some(key)
+    }
+
+    /// Collects the variables that map to keys, by their keys.
+    /// ```
+    /// use convert_case_extras::env::EnvMapper;
+    ///
+    /// let vars = [
+    ///     ("APP_LOG_LEVEL", "debug"),
+    ///     ("APP_DATABASE__URL", "postgres://localhost"),
+    ///     ("PATH", "/usr/bin"),
+    /// ];
+    /// let config = EnvMapper::new().prefix("APP").load_from(vars);
+    /// assert_eq!(config.len(), 2);
+    /// assert_eq!(config["logLevel"], "debug");
+    /// assert_eq!(config["database.url"], "postgres://localhost");
+    /// ```
+    pub fn load_from<I, K, V>(&self, vars: I) -> BTreeMap<String, String>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<str>,
+        V: Into<String>,
+    {
+        vars.into_iter()
+            .filter_map(|(var, value)| Some((self.to_key(var.as_ref())?, value.into())))
+            .collect()
+    }
+
+    /// Collects the variables of the environment of this process that map to keys, by
+    /// their keys.  Variables whose names or values aren't unicode are skipped.  Only
+    /// available with the "std" feature.
+    #[cfg(feature = "std")]
+    pub fn load(&self) -> BTreeMap<String, String> {
+        self.load_from(
+            std::env::vars_os().filter_map(|(var, value)| {
+                Some((var.into_string().ok()?, value.into_string().ok()?))
+            }),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let mapper = EnvMapper::new().prefix("APP");
+        for key in [
+            "port",
+            "database.pool.maxSize",
+            "ipv4Address",
+            "retry.maxAttempts",
+            "a.b.c",
+        ] {
+            let var = mapper.to_var(key);
+            assert_eq!(mapper.to_key(&var).as_deref(), Some(key), "{}", var);
+        }
+    }
+
+    #[test]
+    fn any_key_case() {
+        let mapper = EnvMapper::new();
+        assert_eq!(mapper.to_var("server.max_conns"), "SERVER__MAX_CONNS");
+        assert_eq!(mapper.to_var("server.max-conns"), "SERVER__MAX_CONNS");
+        assert_eq!(mapper.to_var("Server.MaxConns"), "SERVER__MAX_CONNS");
+        assert_eq!(
+            mapper.to_key("SERVER__MAX_CONNS").as_deref(),
+            Some("server.maxConns")
+        );
+    }
+
+    #[test]
+    fn unmapped_vars() {
+        let mapper = EnvMapper::new().prefix("APP");
+        assert_eq!(mapper.to_key("APP"), None);
+        assert_eq!(mapper.to_key("APP_"), None);
+        assert_eq!(mapper.to_key("APPLE"), None);
+        assert_eq!(mapper.to_key("APP_DB____URL"), None);
+        assert_eq!(mapper.to_key("APP_DB__"), None);
+        assert_eq!(mapper.to_key("APP_Db"), None);
+        assert_eq!(mapper.to_key("APP_DB___URL"), None);
+        assert_eq!(mapper.to_key("app_db"), None);
+    }
+
+    #[test]
+    fn other_cases() {
+        let mapper = EnvMapper::new()
+            .prefix("Tool")
+            .separator("-")
+            .var_case(Case::Pascal)
+            .key_case(Case::Kebab);
+        assert_eq!(mapper.to_var("cache.max-age"), "ToolCache-MaxAge");
+        assert_eq!(
+            mapper.to_key("ToolCache-MaxAge").as_deref(),
+            Some("cache.max-age")
+        );
+
+        let mapper = EnvMapper::new();
+        assert_eq!(mapper.to_key("LOG_LEVEL").as_deref(), Some("logLevel"));
+    }
+
+    #[test]
+    fn load_vars() {
+        let mapper = EnvMapper::new().prefix("CCASE_ENV_TEST");
+        let config = mapper.load_from([
+            ("CCASE_ENV_TEST_CACHE__MAX_AGE", "60"),
+            ("CCASE_ENV_TEST_cache", "ignored"),
+            ("OTHER_CACHE", "ignored"),
+        ]);
+        assert_eq!(config.len(), 1);
+        assert_eq!(config["cache.maxAge"], "60");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn load_environment() {
+        assert!(EnvMapper::new().prefix("CCASE_ENV_UNSET").load().is_empty());
+    }
+}
@@ -0,0 +1,679 @@
+//! Renaming files and directories into a case.
+//!
+//! A [`RenamePlanner`] walks a directory tree and plans how to rename every entry in it,
+//! without renaming anything.  The [`RenamePlan`] lists the renames and any names that
+//! would collide, can be printed as a dry run, and can be applied all at once: if any
+//! rename fails, the ones already made are undone.
+//!
+//! This is only available with the "fs" feature.
+//! ```no_run
+//! use convert_case::Case;
+//! use convert_case_extras::fs::RenamePlanner;
+//!
+//! let plan = RenamePlanner::new(Case::Kebab).plan("assets")?;
+//! // assets/My Photo 01.PNG -> assets/my-photo-01.png
+//! print!("{}", plan);
+//! plan.apply()?;
+//! # Ok::<(), std::io::Error>(())
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::format;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::string::{String, ToString};
+use std::vec::Vec;
+
+use convert_case::{Boundary, Case};
+
+use crate::{ExtraConverter, ExtraPattern};
+
+/// Plans renaming the entries of a directory tree into a case.
+///
+/// File names keep their extension, the text after the last `.`, which is lowercased
+/// rather than converted.  Entries whose names start with `.` are left as they are, and
+/// hidden directories aren't walked into.  Symbolic links are renamed but not followed.
+///
+/// Names are compared regardless of case on case-insensitive file systems, where
+/// `Readme.md` and `README.md` are the same file.  Unless it is set with
+/// [`case_insensitive`](RenamePlanner::case_insensitive), whether the file system is case
+/// insensitive is found out by looking up an existing entry of the root directory with
+/// the case of its letters swapped, and a probe file is only written when no entry has an
+/// ASCII letter.  Only the root directory is checked, so a subdirectory mounted from a
+/// different file system is assumed to compare names the same way.
+/// ```
+/// use convert_case::Case;
+/// use convert_case_extras::fs::RenamePlanner;
+/// # let root = std::env::temp_dir().join(format!("ccase-doc-{}", std::process::id()));
+/// # std::fs::create_dir_all(root.join("Raw Scans"))?;
+/// # std::fs::write(root.join("Raw Scans/Page 1.TIFF"), "")?;
+///
+/// let plan = RenamePlanner::new(Case::Snake).directories(false).plan(&root)?;
+/// assert_eq!(plan.renames().len(), 1);
+/// assert!(plan.renames()[0].to.ends_with("Raw Scans/page_1.tiff"));
+/// # std::fs::remove_dir_all(&root)?;
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub struct RenamePlanner {
+    converter: ExtraConverter,
+    recursive: bool,
+    directories: bool,
+    lowercase_extensions: bool,
+    case_insensitive: Option<bool>,
+}
+
+impl RenamePlanner {
+    /// Creates a planner that converts names into `case`, splitting them on the default
+    /// boundaries.
+    pub fn new(case: Case) -> Self {
+        Self::from_converter(ExtraConverter::new().to_case(case))
+    }
+
+    /// Creates a planner that converts names with `converter`.
+    pub fn from_converter(converter: ExtraConverter) -> Self {
+        RenamePlanner {
+            converter,
+            recursive: true,
+            directories: true,
+            lowercase_extensions: true,
+            case_insensitive: None,
+        }
+    }
+
+    /// Splits names on the boundaries of `case`.
+    pub fn from_case(mut self, case: Case) -> Self {
+        self.converter = self.converter.from_case(case);
+        self
+    }
+
+    /// Splits names on the given boundaries.
+    pub fn set_boundaries(mut self, boundaries: &[Boundary]) -> Self {
+        self.converter = self.converter.set_boundaries(boundaries);
+        self
+    }
+
+    /// Mutates the words of each name with `pattern`.
+    pub fn set_pattern<P: ExtraPattern + 'static>(mut self, pattern: P) -> Self {
+        self.converter = self.converter.set_pattern(pattern);
+        self
+    }
+
+    /// Whether to walk into subdirectories, which is the default.
+    pub fn recursive(mut self, recursive: bool) -> Self {
+        self.recursive = recursive;
+        self
+    }
+
+    /// Whether to rename directories as well as files, which is the default.
+    pub fn directories(mut self, directories: bool) -> Self {
+        self.directories = directories;
+        self
+    }
+
+    /// Whether to lowercase extensions, which is the default.  Otherwise they are kept
+    /// as they are.
+    pub fn lowercase_extensions(mut self, lowercase: bool) -> Self {
+        self.lowercase_extensions = lowercase;
+        self
+    }
+
+    /// Sets whether the file system compares names regardless of case, instead of
+    /// finding it out.
+    pub fn case_insensitive(mut self, insensitive: bool) -> Self {
+        self.case_insensitive = Some(insensitive);
+        self
+    }
+
+    /// The new name of an entry, which is `name` when it is left as it is.
+    fn rename(&self, name: &str, is_dir: bool) -> String {
+        if name.starts_with('.') || (is_dir && !self.directories) {
+            return name.to_string();
+        }
+        let (stem, extension) = match name.rsplit_once('.') {
+            Some((stem, extension)) if !is_dir => (stem, Some(extension)),
+            _ => (name, None),
+        };
+        let stem = self.converter.convert(stem);
+        if stem.is_empty() {
+            return name.to_string();
+        }
+        match extension {
+            Some(extension) if self.lowercase_extensions => {
+                format!("{}.{}", stem, extension.to_lowercase())
+            }
+            Some(extension) => format!("{}.{}", stem, extension),
+            None => stem,
+        }
+    }
+
+    /// Plans renaming every entry below `root`, but not `root` itself.
+    ///
+    /// Unless it was set with [`case_insensitive`](Self::case_insensitive), whether the
+    /// file system compares names regardless of case is found out by looking up an entry
+    /// of `root` with the case of its letters swapped.  Only if no entry of `root` has an
+    /// ASCII letter is an empty file briefly created in `root` to look up instead.
+    pub fn plan<P: AsRef<Path>>(&self, root: P) -> io::Result<RenamePlan> {
+        let root = root.as_ref();
+        let case_insensitive = match self.case_insensitive {
+            Some(insensitive) => insensitive,
+            None => is_case_insensitive(root)?,
+        };
+        let mut plan = RenamePlan {
+            renames: Vec::new(),
+            collisions: Vec::new(),
+            case_insensitive,
+        };
+        self.plan_dir(root, 0, &mut plan)?;
+        // Deepest first, so that every path stays valid until its own rename
+        plan.renames
+            .sort_by(|a, b| b.depth.cmp(&a.depth).then_with(|| a.from.cmp(&b.from)));
+        Ok(plan)
+    }
+
+    fn plan_dir(&self, dir: &Path, depth: usize, plan: &mut RenamePlan) -> io::Result<()> {
+        let mut entries = fs::read_dir(dir)
+            .and_then(|entries| entries.collect::<io::Result<Vec<_>>>())
+            .map_err(|e| with_path(e, dir))?;
+        entries.sort_by_key(|entry| entry.file_name());
+
+        // The original and new name of every entry
+        let mut names: Vec<(String, String)> = Vec::new();
+        for entry in &entries {
+            let is_dir = entry
+                .file_type()
+                .map_err(|e| with_path(e, &entry.path()))?
+                .is_dir();
+            let Ok(name) = entry.file_name().into_string() else {
+                names.push((
+                    entry.file_name().to_string_lossy().into_owned(),
+                    String::new(),
+                ));
+                continue;
+            };
+            let renamed = self.rename(&name, is_dir);
+            if renamed != name {
+                plan.renames.push(Rename {
+                    from: entry.path(),
+                    to: dir.join(&renamed),
+                    depth,
+                });
+            }
+            if is_dir && self.recursive && !name.starts_with('.') {
+                self.plan_dir(&entry.path(), depth + 1, plan)?;
+            }
+            names.push((name, renamed));
+        }
+
+        // The entries with each new name, in order
+        let mut by_name: BTreeMap<String, Vec<&(String, String)>> = BTreeMap::new();
+        for entry in names.iter().filter(|(_, renamed)| !renamed.is_empty()) {
+            let key = if plan.case_insensitive {
+                entry.1.to_lowercase()
+            } else {
+                entry.1.clone()
+            };
+            by_name.entry(key).or_default().push(entry);
+        }
+        let mut collisions: Vec<&Vec<&(String, String)>> = by_name
+            .values()
+            .filter(|entries| {
+                entries.len() > 1 && entries.iter().any(|(name, renamed)| name != renamed)
+            })
+            .collect();
+        collisions.sort_by_key(|entries| &entries[0].0);
+        for entries in collisions {
+            plan.collisions.push(NameCollision {
+                dir: dir.to_path_buf(),
+                names: entries.iter().map(|(name, _)| name.clone()).collect(),
+                renamed: entries[0].1.clone(),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Finds out whether the file system of `dir` compares names regardless of case, by
+/// looking for an entry with the case of its ASCII letters swapped.  When no entry has
+/// a letter, a file is created to look for, and removed again.
+fn is_case_insensitive(dir: &Path) -> io::Result<bool> {
+    let names: Vec<String> = fs::read_dir(dir)
+        .and_then(|entries| entries.collect::<io::Result<Vec<_>>>())
+        .map_err(|e| with_path(e, dir))?
+        .into_iter()
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .collect();
+    let swapped = names.iter().map(|name| {
+        name.chars()
+            .map(|c| {
+                if c.is_ascii_lowercase() {
+                    c.to_ascii_uppercase()
+                } else {
+                    c.to_ascii_lowercase()
+                }
+            })
+            .collect::<String>()
+    });
+    // A swapped name that is also an entry can be found on any file system
+    for (name, swapped) in names.iter().zip(swapped) {
+        if &swapped != name && !names.contains(&swapped) {
+            return Ok(fs::symlink_metadata(dir.join(swapped)).is_ok());
+        }
+    }
+
+    let probe = dir.join(format!(".ccase-probe-{}", std::process::id()));
+    fs::write(&probe, "").map_err(|e| with_path(e, dir))?;
+    let upper = dir.join(format!(".CCASE-PROBE-{}", std::process::id()));
+    let insensitive = fs::symlink_metadata(upper).is_ok();
+    fs::remove_file(&probe).map_err(|e| with_path(e, &probe))?;
+    Ok(insensitive)
+}
+
+fn with_path(e: io::Error, path: &Path) -> io::Error {
+    io::Error::new(e.kind(), format!("{}: {}", path.display(), e))
+}
+
+/// Renaming a file or directory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rename {
+    /// The path of the entry.
+    pub from: PathBuf,
+    /// The path of the entry after it is renamed, within its directory as it was before
+    /// any renames.
+    pub to: PathBuf,
+    // The number of directories between the root and the entry
+    depth: usize,
+}
+
+impl Rename {
+    /// Returns true if the names differ only by case, which is a rename on a case
+    /// insensitive file system that can't be made in one step.
+    pub fn is_case_only(&self) -> bool {
+        let name = |path: &Path| {
+            path.file_name()
+                .map(|name| name.to_string_lossy().to_lowercase())
+        };
+        name(&self.from) == name(&self.to)
+    }
+}
+
+/// Entries of the same directory that would have the same name after renaming.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NameCollision {
+    /// The directory of the entries.
+    pub dir: PathBuf,
+    /// The original names of the entries, in order.  Entries that aren't renamed are
+    /// included.
+    pub names: Vec<String>,
+    /// The name that every entry would have, regardless of case on case-insensitive
+    /// file systems.
+    pub renamed: String,
+}
+
+impl fmt::Display for NameCollision {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let names: Vec<String> = self
+            .names
+            .iter()
+            .map(|name| format!("`{}`", name))
+            .collect();
+        write!(
+            f,
+            "{} in `{}` would all be named `{}`",
+            names.join(", "),
+            self.dir.display(),
+            self.renamed
+        )
+    }
+}
+
+impl std::error::Error for NameCollision {}
+
+/// The renames planned by a [`RenamePlanner`].
+///
+/// Displaying a plan lists each rename on its own line, followed by any collisions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenamePlan {
+    renames: Vec<Rename>,
+    collisions: Vec<NameCollision>,
+    case_insensitive: bool,
+}
+
+impl RenamePlan {
+    /// The renames, in the order they are made: deepest entries first, and then by path.
+    pub fn renames(&self) -> &[Rename] {
+        &self.renames
+    }
+
+    /// The names that would collide.  A plan with collisions can't be applied.
+    pub fn collisions(&self) -> &[NameCollision] {
+        &self.collisions
+    }
+
+    /// Returns true if names were compared regardless of case.
+    pub fn is_case_insensitive(&self) -> bool {
+        self.case_insensitive
+    }
+
+    /// Returns true if nothing would be renamed.
+    pub fn is_empty(&self) -> bool {
+        self.renames.is_empty()
+    }
+
+    /// Makes every rename.
+    ///
+    /// Each entry is first moved to a temporary name in its directory, so that entries
+    /// can swap names and names can change only by case.  If any rename fails, or would
+    /// replace an entry that appeared since planning, every rename already made is undone
+    /// and the error is returned.  Nothing is renamed if there are collisions.
+    pub fn apply(&self) -> io::Result<()> {
+        if let Some(collision) = self.collisions.first() {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                collision.to_string(),
+            ));
+        }
+
+        // The renames made, from the current path to the original path
+        let mut made: Vec<(PathBuf, PathBuf)> = Vec::new();
+        let result = self.rename_all(&mut made);
+        if let Err(e) = result {
+            for (current, original) in made.iter().rev() {
+                if let Err(undo) = fs::rename(current, original) {
+                    return Err(io::Error::new(
+                        e.kind(),
+                        format!(
+                            "{}, and undoing the rename of `{}` to `{}` failed: {}",
+                            e,
+                            original.display(),
+                            current.display(),
+                            undo
+                        ),
+                    ));
+                }
+            }
+            return Err(e);
+        }
+        Ok(())
+    }
+
+    fn rename_all(&self, made: &mut Vec<(PathBuf, PathBuf)>) -> io::Result<()> {
+        for level in self.renames.chunk_by(|a, b| a.depth == b.depth) {
+            let mut temps = Vec::new();
+            for rename in level {
+                let temp = temp_path(&rename.from);
+                move_entry(&rename.from, &temp, made)?;
+                temps.push(temp);
+            }
+            for (rename, temp) in level.iter().zip(temps) {
+                if fs::symlink_metadata(&rename.to).is_ok() {
+                    return Err(io::Error::new(
+                        io::ErrorKind::AlreadyExists,
+                        format!("{}: already exists", rename.to.display()),
+                    ));
+                }
+                move_entry(&temp, &rename.to, made)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A path next to `path` that doesn't exist.
+fn temp_path(path: &Path) -> PathBuf {
+    let name = path.file_name().unwrap_or_default().to_string_lossy();
+    (0..)
+        .map(|i| path.with_file_name(format!(".{}.ccase-{}-{}", name, std::process::id(), i)))
+        .find(|temp| fs::symlink_metadata(temp).is_err())
+        .unwrap()
+}
+
+fn move_entry(from: &Path, to: &Path, made: &mut Vec<(PathBuf, PathBuf)>) -> io::Result<()> {
+    fs::rename(from, to).map_err(|e| with_path(e, from))?;
+    made.push((to.to_path_buf(), from.to_path_buf()));
+    Ok(())
+}
+
+impl fmt::Display for RenamePlan {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for rename in &self.renames {
+            write!(f, "{} -> {}", rename.from.display(), rename.to.display())?;
+            if self.case_insensitive && rename.is_case_only() {
+                write!(f, " (case only)")?;
+            }
+            writeln!(f)?;
+        }
+        for collision in &self.collisions {
+            writeln!(f, "collision: {}", collision)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::vec;
+
+    /// A directory with the given files, removed when dropped.
+    struct Tree(PathBuf);
+
+    impl Tree {
+        fn new(test: &str, files: &[&str]) -> Tree {
+            let root =
+                std::env::temp_dir().join(format!("ccase-fs-{}-{}", test, std::process::id()));
+            let _ = fs::remove_dir_all(&root);
+            for file in files {
+                let path = root.join(file);
+                fs::create_dir_all(path.parent().unwrap()).unwrap();
+                fs::write(&path, file).unwrap();
+            }
+            Tree(root)
+        }
+
+        /// Every path below the root, relative to it.
+        fn paths(&self) -> Vec<String> {
+            fn walk(dir: &Path, root: &Path, paths: &mut Vec<String>) {
+                for entry in fs::read_dir(dir).unwrap() {
+                    let path = entry.unwrap().path();
+                    paths.push(
+                        path.strip_prefix(root)
+                            .unwrap()
+                            .to_string_lossy()
+                            .into_owned(),
+                    );
+                    if path.is_dir() {
+                        walk(&path, root, paths);
+                    }
+                }
+            }
+            let mut paths = Vec::new();
+            walk(&self.0, &self.0, &mut paths);
+            paths.sort();
+            paths
+        }
+    }
+
+    impl Drop for Tree {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn plan_and_apply() {
+        let tree = Tree::new(
+            "apply",
+            &[
+                "My Photo 01.PNG",
+                "Raw Scans/Page One.tiff",
+                ".Hidden Dir/Some File",
+                "Readme.MD",
+            ],
+        );
+        let plan = RenamePlanner::new(Case::Kebab)
+            .case_insensitive(true)
+            .plan(&tree.0)
+            .unwrap();
+        assert!(plan.collisions().is_empty());
+
+        let renames: Vec<(&Path, &Path)> = plan
+            .renames()
+            .iter()
+            .map(|r| {
+                (
+                    r.from.strip_prefix(&tree.0).unwrap(),
+                    r.to.strip_prefix(&tree.0).unwrap(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            renames,
+            [
+                (
+                    Path::new("Raw Scans/Page One.tiff"),
+                    Path::new("Raw Scans/page-one.tiff")
+                ),
+                (Path::new("My Photo 01.PNG"), Path::new("my-photo-01.png")),
+                (Path::new("Raw Scans"), Path::new("raw-scans")),
+                (Path::new("Readme.MD"), Path::new("readme.md")),
+            ]
+        );
+        assert!(plan
+            .to_string()
+            .lines()
+            .nth(3)
+            .unwrap()
+            .ends_with("readme.md (case only)"));
+
+        plan.apply().unwrap();
+        assert_eq!(
+            tree.paths(),
+            [
+                ".Hidden Dir",
+                ".Hidden Dir/Some File",
+                "my-photo-01.png",
+                "raw-scans",
+                "raw-scans/page-one.tiff",
+                "readme.md"
+            ]
+        );
+        assert_eq!(
+            fs::read_to_string(tree.0.join("readme.md")).unwrap(),
+            "Readme.MD"
+        );
+        assert!(RenamePlanner::new(Case::Kebab)
+            .plan(&tree.0)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn options() {
+        let tree = Tree::new("options", &["Top Dir/Inner File.TXT", "Top File.TXT"]);
+        let plan = RenamePlanner::new(Case::Snake)
+            .recursive(false)
+            .directories(false)
+            .lowercase_extensions(false)
+            .plan(&tree.0)
+            .unwrap();
+        assert_eq!(plan.renames().len(), 1);
+        assert!(plan.renames()[0].to.ends_with("top_file.TXT"));
+    }
+
+    #[test]
+    fn collisions() {
+        let tree = Tree::new(
+            "collisions",
+            &["a b.txt", "a-b.txt", "A-B.TXT", "My-Dir/x", "my dir"],
+        );
+        let plan = RenamePlanner::new(Case::Kebab)
+            .directories(false)
+            .case_insensitive(false)
+            .plan(&tree.0)
+            .unwrap();
+        assert_eq!(plan.collisions().len(), 1);
+        assert_eq!(
+            plan.collisions()[0].names,
+            ["A-B.TXT", "a b.txt", "a-b.txt"]
+        );
+        assert_eq!(plan.collisions()[0].renamed, "a-b.txt");
+
+        let plan = RenamePlanner::new(Case::Kebab)
+            .directories(false)
+            .case_insensitive(true)
+            .plan(&tree.0)
+            .unwrap();
+        assert_eq!(plan.collisions().len(), 2);
+        assert_eq!(plan.collisions()[1].names, ["My-Dir", "my dir"]);
+        assert!(plan.to_string().contains("collision: `My-Dir`, `my dir`"));
+
+        let before = tree.paths();
+        assert_eq!(
+            plan.apply().unwrap_err().kind(),
+            io::ErrorKind::AlreadyExists
+        );
+        assert_eq!(tree.paths(), before);
+    }
+
+    #[test]
+    fn swap_names() {
+        let tree = Tree::new("swap", &["x/a", "x/b"]);
+        let plan = RenamePlan {
+            renames: vec![
+                Rename {
+                    from: tree.0.join("x/a"),
+                    to: tree.0.join("x/b"),
+                    depth: 1,
+                },
+                Rename {
+                    from: tree.0.join("x/b"),
+                    to: tree.0.join("x/a"),
+                    depth: 1,
+                },
+            ],
+            collisions: Vec::new(),
+            case_insensitive: false,
+        };
+        plan.apply().unwrap();
+        assert_eq!(fs::read_to_string(tree.0.join("x/a")).unwrap(), "x/b");
+        assert_eq!(fs::read_to_string(tree.0.join("x/b")).unwrap(), "x/a");
+    }
+
+    #[test]
+    fn rollback() {
+        let tree = Tree::new("rollback", &["Dir One/File One", "File Two", "File Three"]);
+        let plan = RenamePlanner::new(Case::Snake).plan(&tree.0).unwrap();
+        assert_eq!(plan.renames().len(), 4);
+
+        // The last rename fails after the others are made
+        fs::remove_file(tree.0.join("File Two")).unwrap();
+        let before = tree.paths();
+        let err = plan.apply().unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+        assert!(err.to_string().contains("File Two"));
+        assert_eq!(tree.paths(), before);
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn detect_case_sensitive() {
+        let tree = Tree::new("detect", &["File"]);
+        let modified = fs::metadata(&tree.0).unwrap().modified().unwrap();
+        let plan = RenamePlanner::new(Case::Snake).plan(&tree.0).unwrap();
+        assert!(!plan.is_case_insensitive());
+        assert_eq!(tree.paths(), ["File"]);
+        // Nothing was created in the directory to find out
+        assert_eq!(fs::metadata(&tree.0).unwrap().modified().unwrap(), modified);
+
+        let tree = Tree::new("detect-pair", &["File", "fILE", "Other"]);
+        let plan = RenamePlanner::new(Case::Snake).plan(&tree.0).unwrap();
+        assert!(!plan.is_case_insensitive());
+
+        let tree = Tree::new("detect-probe", &["01", "02/x"]);
+        let plan = RenamePlanner::new(Case::Snake).plan(&tree.0).unwrap();
+        assert!(!plan.is_case_insensitive());
+        assert_eq!(tree.paths(), ["01", "02", "02/x"]);
+    }
+}
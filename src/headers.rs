@@ -0,0 +1,430 @@
+//! Normalizing the header rows of CSV and TSV files.
+//!
+//! Each header is converted into a case after its punctuation is removed, so that
+//! `"Customer ID #"`, `"customer-id"` and `"CustomerId"` all become `customer_id`.
+//! Headers that would be empty are named after their column, and duplicates are told
+//! apart by a number.
+//!
+//! ```
+//! use convert_case::Case;
+//! use convert_case_extras::headers::normalize_headers;
+//!
+//! let headers = normalize_headers(&["Customer ID #", "customer-id", "CustomerId", "#"], Case::Snake);
+//! assert_eq!(headers.names(), ["customer_id", "customer_id_2", "customer_id_3", "column_4"]);
+//! assert_eq!(headers.get("customer-id"), Some("customer_id_2"));
+//! ```
+//!
+//! With the "csv" feature, [`NormalizedCsv`] normalizes the header row of a CSV stream
+//! as it is read.
+
+use alloc::collections::BTreeSet;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+
+use convert_case::{Case, Casing};
+
+#[cfg(feature = "csv")]
+use std::io::{self, BufRead, Read};
+
+/// Normalizes a header row into `case`.
+///
+/// Every character that isn't a letter or digit is removed and separates words, and the
+/// words are converted into `case`.  A header without any letters or digits is named
+/// `column` followed by its column number, counting from 1.  A header that is the same
+/// as an earlier one gets the lowest number from 2 that makes it unique.
+pub fn normalize_headers<S: AsRef<str>>(headers: &[S], case: Case) -> HeaderMap {
+    let mut taken = BTreeSet::new();
+    let mut mapping = Vec::with_capacity(headers.len());
+    for (i, original) in headers.iter().enumerate() {
+        let original = original.as_ref();
+        let words: Vec<&str> = original
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|word| !word.is_empty())
+            .collect();
+        let base = if words.is_empty() {
+            format!("column {}", i + 1).to_case(case)
+        } else {
+            words.join(" ").to_case(case)
+        };
+
+        let mut normalized = base.clone();
+        let mut n = 2;
+        while taken.contains(&normalized) {
+            normalized = format!("{} {}", base, n).to_case(case);
+            n += 1;
+        }
+        taken.insert(normalized.clone());
+        mapping.push(Header {
+            original: original.to_string(),
+            normalized,
+        });
+    }
+    HeaderMap { headers: mapping }
+}
+
+/// A header and what it was normalized to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Header {
+    /// The header as it was.
+    pub original: String,
+    /// The normalized header.
+    pub normalized: String,
+}
+
+/// The headers of a row, in order, and what they were normalized to.
+///
+/// Displaying the map writes a table with a line for each header.
+/// ```
+/// use convert_case::Case;
+/// use convert_case_extras::headers::normalize_headers;
+///
+/// let headers = normalize_headers(&["Order #", "Unit Price ($)"], Case::Camel);
+/// assert_eq!(headers.to_string(), "\
+/// Order #        -> order
+/// Unit Price ($) -> unitPrice
+/// ");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HeaderMap {
+    headers: Vec<Header>,
+}
+
+impl HeaderMap {
+    /// Iterates over the headers in order.
+    pub fn iter(&self) -> impl Iterator<Item = &Header> {
+        self.headers.iter()
+    }
+
+    /// The normalized headers, in order.
+    pub fn names(&self) -> Vec<&str> {
+        self.iter()
+            .map(|header| header.normalized.as_str())
+            .collect()
+    }
+
+    /// Returns the normalized header of the first column named `original`.
+    pub fn get(&self, original: &str) -> Option<&str> {
+        self.iter()
+            .find(|header| header.original == original)
+            .map(|header| header.normalized.as_str())
+    }
+
+    /// The number of headers.
+    pub fn len(&self) -> usize {
+        self.headers.len()
+    }
+
+    /// Returns true if there are no headers.
+    pub fn is_empty(&self) -> bool {
+        self.headers.is_empty()
+    }
+}
+
+impl fmt::Display for HeaderMap {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let width = self
+            .iter()
+            .map(|header| header.original.chars().count())
+            .max()
+            .unwrap_or(0);
+        for header in self.iter() {
+            writeln!(
+                f,
+                "{:width$} -> {}",
+                header.original,
+                header.normalized,
+                width = width
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// A reader of CSV or TSV data whose header row is normalized.
+///
+/// The header row is read and normalized when the reader is created, and reading then
+/// produces the normalized header row followed by the rest of the data unchanged, so it
+/// can be passed on to any CSV parser.  Headers are parsed as in RFC 4180: fields can be
+/// quoted with `"`, and quoted fields can contain delimiters, quotes written as `""`, and
+/// line breaks.  A byte order mark before the header row is removed.  Only available
+/// with the "csv" feature.
+/// ```
+/// use std::io::Read;
+/// use convert_case::Case;
+/// use convert_case_extras::headers::NormalizedCsv;
+///
+/// let data = "\"Customer ID #\",Order Date,\"Total, USD\"\r\n17,2024-01-02,\"1,200\"\r\n";
+/// let mut csv = NormalizedCsv::new(data.as_bytes(), Case::Snake)?;
+/// assert_eq!(csv.headers().names(), ["customer_id", "order_date", "total_usd"]);
+///
+/// let mut normalized = String::new();
+/// csv.read_to_string(&mut normalized)?;
+/// assert_eq!(normalized, "customer_id,order_date,total_usd\r\n17,2024-01-02,\"1,200\"\r\n");
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[cfg(feature = "csv")]
+pub struct NormalizedCsv<R> {
+    inner: R,
+    headers: HeaderMap,
+    // The normalized header row, and how much of it has been read
+    header_row: Vec<u8>,
+    pos: usize,
+}
+
+#[cfg(feature = "csv")]
+impl<R: BufRead> NormalizedCsv<R> {
+    /// Reads the header row of comma separated `inner` and normalizes it into `case`.
+    pub fn new(inner: R, case: Case) -> io::Result<Self> {
+        Self::with_delimiter(inner, case, b',')
+    }
+
+    /// Reads the header row of `inner`, whose fields are separated by `delimiter`, and
+    /// normalizes it into `case`.  Use `b'\t'` for TSV.
+    pub fn with_delimiter(mut inner: R, case: Case, delimiter: u8) -> io::Result<Self> {
+        let (fields, line_break) = read_record(&mut inner, delimiter)?;
+        let headers = normalize_headers(&fields, case);
+
+        let mut header_row = Vec::new();
+        for (i, name) in headers.names().iter().enumerate() {
+            if i > 0 {
+                header_row.push(delimiter);
+            }
+            write_field(&mut header_row, name, delimiter);
+        }
+        header_row.extend_from_slice(line_break.as_bytes());
+
+        Ok(NormalizedCsv {
+            inner,
+            headers,
+            header_row,
+            pos: 0,
+        })
+    }
+
+    /// The headers and what they were normalized to.
+    pub fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    /// Returns the underlying reader, positioned after the header row.  Any part of the
+    /// normalized header row that hasn't been read is lost.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+#[cfg(feature = "csv")]
+impl<R: BufRead> Read for NormalizedCsv<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.pos < self.header_row.len() {
+            let n = (&self.header_row[self.pos..]).read(buf)?;
+            self.pos += n;
+            Ok(n)
+        } else {
+            self.inner.read(buf)
+        }
+    }
+}
+
+#[cfg(feature = "csv")]
+impl<R: BufRead> BufRead for NormalizedCsv<R> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        if self.pos < self.header_row.len() {
+            Ok(&self.header_row[self.pos..])
+        } else {
+            self.inner.fill_buf()
+        }
+    }
+
+    fn consume(&mut self, amount: usize) {
+        if self.pos < self.header_row.len() {
+            self.pos += amount;
+        } else {
+            self.inner.consume(amount);
+        }
+    }
+}
+
+/// Reads a record and the line break that ended it, which is empty at the end of the
+/// data.
+#[cfg(feature = "csv")]
+fn read_record<R: BufRead>(
+    reader: &mut R,
+    delimiter: u8,
+) -> io::Result<(Vec<String>, &'static str)> {
+    let mut line = Vec::new();
+    let mut quoted = false;
+    loop {
+        let start = line.len();
+        if reader.read_until(b'\n', &mut line)? == 0 {
+            break;
+        }
+        quoted ^= line[start..].iter().filter(|&&b| b == b'"').count() % 2 == 1;
+        if !quoted {
+            break;
+        }
+    }
+    if quoted {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "unterminated quoted header",
+        ));
+    }
+
+    let line_break = if line.ends_with(b"\r\n") {
+        "\r\n"
+    } else if line.ends_with(b"\n") {
+        "\n"
+    } else {
+        ""
+    };
+    line.truncate(line.len() - line_break.len());
+    let line = line.strip_prefix("\u{feff}".as_bytes()).unwrap_or(&line);
+
+    let mut fields = Vec::new();
+    let mut field = Vec::new();
+    let mut bytes = line.iter().copied().peekable();
+    let mut in_quotes = false;
+    while let Some(b) = bytes.next() {
+        match b {
+            b'"' if in_quotes && bytes.peek() == Some(&b'"') => {
+                field.push(b'"');
+                bytes.next();
+            }
+            b'"' => in_quotes = !in_quotes,
+            _ if b == delimiter && !in_quotes => fields.push(core::mem::take(&mut field)),
+            _ => field.push(b),
+        }
+    }
+    if !line.is_empty() {
+        fields.push(field);
+    }
+
+    let fields = fields
+        .into_iter()
+        .map(|field| {
+            String::from_utf8(field).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+        })
+        .collect::<io::Result<_>>()?;
+    Ok((fields, line_break))
+}
+
+#[cfg(feature = "csv")]
+fn write_field(row: &mut Vec<u8>, field: &str, delimiter: u8) {
+    if field
+        .bytes()
+        .any(|b| b == delimiter || matches!(b, b'"' | b'\r' | b'\n'))
+    {
+        row.push(b'"');
+        row.extend_from_slice(field.replace('"', "\"\"").as_bytes());
+        row.push(b'"');
+    } else {
+        row.extend_from_slice(field.as_bytes());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn vendor_headers() {
+        let headers = normalize_headers(
+            &[
+                "Customer ID #",
+                "customer-id",
+                "CustomerId",
+                "customer_id_2",
+                "E-mail Address",
+            ],
+            Case::Snake,
+        );
+        assert_eq!(
+            headers.names(),
+            [
+                "customer_id",
+                "customer_id_2",
+                "customer_id_3",
+                "customer_id_2_2",
+                "e_mail_address"
+            ]
+        );
+    }
+
+    #[test]
+    fn other_cases() {
+        let headers = normalize_headers(&["First Name", "first_name", "", "  "], Case::Camel);
+        assert_eq!(
+            headers.names(),
+            ["firstName", "firstName2", "column3", "column4"]
+        );
+
+        let headers = normalize_headers(&["Größe (cm)", "Größe"], Case::UpperKebab);
+        assert_eq!(headers.names(), ["GRÖSSE-CM", "GRÖSSE"]);
+    }
+
+    #[test]
+    fn mapping() {
+        let headers = normalize_headers(&["A", "A", "Ünit"], Case::Snake);
+        assert_eq!(headers.len(), 3);
+        assert_eq!(headers.get("A"), Some("a"));
+        assert_eq!(headers.get("B"), None);
+        assert_eq!(
+            headers.to_string(),
+            "A    -> a\nA    -> a_2\nÜnit -> ünit\n"
+        );
+        assert!(normalize_headers::<&str>(&[], Case::Snake).is_empty());
+    }
+
+    #[cfg(feature = "csv")]
+    fn normalize_csv(data: &str, delimiter: u8) -> io::Result<String> {
+        let mut csv = NormalizedCsv::with_delimiter(data.as_bytes(), Case::Snake, delimiter)?;
+        let mut normalized = String::new();
+        csv.read_to_string(&mut normalized)?;
+        Ok(normalized)
+    }
+
+    #[test]
+    #[cfg(feature = "csv")]
+    fn csv_headers() {
+        assert_eq!(
+            normalize_csv(
+                "\u{feff}Name,\"Line\nBreak\",\"Say \"\"Hi\"\"\"\na,b,c\n",
+                b','
+            )
+            .unwrap(),
+            "name,line_break,say_hi\na,b,c\n"
+        );
+        assert_eq!(
+            normalize_csv("Item Name\tQty (units)\nx\t1\n", b'\t').unwrap(),
+            "item_name\tqty_units\nx\t1\n"
+        );
+        assert_eq!(normalize_csv("Only Header", b',').unwrap(), "only_header");
+        assert_eq!(normalize_csv("", b',').unwrap(), "");
+        assert_eq!(normalize_csv("a,,b\n", b',').unwrap(), "a,column_2,b\n");
+
+        let err = normalize_csv("\"Unterminated\nheader", b',').unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    #[cfg(feature = "csv")]
+    fn csv_quotes_delimiters() {
+        let mut csv = NormalizedCsv::with_delimiter(
+            "a b;c\n1;2\n".as_bytes(),
+            Case::Custom {
+                boundaries: &[convert_case::Boundary::Space],
+                pattern: convert_case::Pattern::Lowercase,
+                delim: ";",
+            },
+            b';',
+        )
+        .unwrap();
+        let mut lines = String::new();
+        csv.read_line(&mut lines).unwrap();
+        assert_eq!(lines, "\"a;b\";c\n");
+        assert_eq!(csv.into_inner(), "1;2\n".as_bytes());
+    }
+}
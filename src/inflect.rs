@@ -0,0 +1,527 @@
+//! English inflections in the style of Rails' `ActiveSupport::Inflector`.
+//!
+//! Strings can be in any case.  They are split into words with the default boundaries
+//! of `convert_case`, so pluralizing and singularizing only change the last word and
+//! keep the rest of the string, including its casing and delimiters, as it was.
+//!
+//! ```
+//! use convert_case_extras::inflect;
+//!
+//! assert_eq!(inflect::pluralize("blog_post"), "blog_posts");
+//! assert_eq!(inflect::pluralize("SalesPerson"), "SalesPeople");
+//! assert_eq!(inflect::singularize("USER_CATEGORIES"), "USER_CATEGORY");
+//! assert_eq!(inflect::humanize("employee_id"), "Employee");
+//! assert_eq!(inflect::tableize("LineItem"), "line_items");
+//! assert_eq!(inflect::classify("line_items"), "LineItem");
+//! assert_eq!(inflect::foreign_key("Admin::LineItem"), "line_item_id");
+//! assert_eq!(inflect::ordinalize(22), "22nd");
+//! ```
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use convert_case::{Boundary, Case, Casing};
+
+/// Words whose singular and plural forms are the same.
+pub const UNCOUNTABLES: &[&str] = &[
+    "equipment",
+    "fish",
+    "information",
+    "jeans",
+    "money",
+    "police",
+    "rice",
+    "series",
+    "sheep",
+    "species",
+];
+
+/// Words with irregular plurals, as `(singular, plural)`.
+pub const IRREGULARS: &[(&str, &str)] = &[
+    ("child", "children"),
+    ("foot", "feet"),
+    ("goose", "geese"),
+    ("man", "men"),
+    ("move", "moves"),
+    ("person", "people"),
+    ("sex", "sexes"),
+    ("tooth", "teeth"),
+    ("woman", "women"),
+    ("zombie", "zombies"),
+];
+
+/// Returns the plural form of the last word of `s`.
+/// ```
+/// use convert_case_extras::inflect::pluralize;
+///
+/// assert_eq!(pluralize("query"), "queries");
+/// assert_eq!(pluralize("userAddress"), "userAddresses");
+/// assert_eq!(pluralize("Octopus"), "Octopi");
+/// assert_eq!(pluralize("sheep"), "sheep");
+/// assert_eq!(pluralize("posts"), "posts");
+/// ```
+pub fn pluralize(s: &str) -> String {
+    inflect_last_word(s, plural)
+}
+
+/// Returns the singular form of the last word of `s`.
+/// ```
+/// use convert_case_extras::inflect::singularize;
+///
+/// assert_eq!(singularize("queries"), "query");
+/// assert_eq!(singularize("user-addresses"), "user-address");
+/// assert_eq!(singularize("People"), "Person");
+/// assert_eq!(singularize("analyses"), "analysis");
+/// assert_eq!(singularize("post"), "post");
+/// ```
+pub fn singularize(s: &str) -> String {
+    inflect_last_word(s, singular)
+}
+
+/// Turns `s` into words for people to read: a trailing `id` word is removed, and the
+/// words are written in sentence case.
+/// ```
+/// use convert_case_extras::inflect::humanize;
+///
+/// assert_eq!(humanize("employee_salary"), "Employee salary");
+/// assert_eq!(humanize("authorId"), "Author");
+/// assert_eq!(humanize("ID"), "Id");
+/// ```
+pub fn humanize(s: &str) -> String {
+    let mut words = words(&s);
+    if words.len() > 1 && words.last().is_some_and(|w| w.eq_ignore_ascii_case("id")) {
+        words.pop();
+    }
+    words
+        .join(" ")
+        .from_case(Case::Lower)
+        .to_case(Case::Sentence)
+}
+
+/// Turns a type name into the name of its table: snake case, with the last word
+/// pluralized.
+/// ```
+/// use convert_case_extras::inflect::tableize;
+///
+/// assert_eq!(tableize("RawScaledScorer"), "raw_scaled_scorers");
+/// assert_eq!(tableize("fancyCategory"), "fancy_categories");
+/// ```
+pub fn tableize(s: &str) -> String {
+    pluralize(&s.to_case(Case::Snake))
+}
+
+/// Turns a table name into the name of its type: pascal case, with the last word
+/// singularized.  Any schema before a `.` is removed.
+/// ```
+/// use convert_case_extras::inflect::classify;
+///
+/// assert_eq!(classify("egg_and_hams"), "EggAndHam");
+/// assert_eq!(classify("public.people"), "Person");
+/// ```
+pub fn classify(s: &str) -> String {
+    let table = s.rsplit('.').next().unwrap_or(s);
+    singularize(table).to_case(Case::Pascal)
+}
+
+/// Turns a type name into the name of a foreign key to its table: snake case with an
+/// `_id` suffix.  Any module path before a `::` is removed.
+/// ```
+/// use convert_case_extras::inflect::foreign_key;
+///
+/// assert_eq!(foreign_key("Message"), "message_id");
+/// assert_eq!(foreign_key("Admin::BlogPost"), "blog_post_id");
+/// ```
+pub fn foreign_key(s: &str) -> String {
+    let name = s.rsplit("::").next().unwrap_or(s);
+    format!("{}_id", name.to_case(Case::Snake))
+}
+
+/// Returns the suffix of the ordinal form of `n`, like `"st"` for `1` or `"th"` for `11`.
+/// ```
+/// use convert_case_extras::inflect::ordinal;
+///
+/// assert_eq!(ordinal(1), "st");
+/// assert_eq!(ordinal(12), "th");
+/// assert_eq!(ordinal(-23), "rd");
+/// ```
+pub fn ordinal(n: i64) -> &'static str {
+    let n = n.unsigned_abs();
+    if (11..=13).contains(&(n % 100)) {
+        return "th";
+    }
+    match n % 10 {
+        1 => "st",
+        2 => "nd",
+        3 => "rd",
+        _ => "th",
+    }
+}
+
+/// Returns the ordinal form of `n`, like `"1st"` or `"11th"`.
+/// ```
+/// use convert_case_extras::inflect::ordinalize;
+///
+/// assert_eq!(ordinalize(1), "1st");
+/// assert_eq!(ordinalize(1002), "1002nd");
+/// assert_eq!(ordinalize(113), "113th");
+/// ```
+pub fn ordinalize(n: i64) -> String {
+    format!("{}{}", n, ordinal(n))
+}
+
+fn words<'a>(s: &'a &str) -> Vec<&'a str> {
+    convert_case::split(s, &Boundary::defaults())
+        .into_iter()
+        .filter(|w| !w.is_empty())
+        .collect()
+}
+
+/// Replaces the last word of `s` with `f` applied to it in lowercase, in the casing of
+/// the original word.
+fn inflect_last_word(s: &str, f: fn(&str) -> String) -> String {
+    let Some(&last) = words(&s).last() else {
+        return s.to_string();
+    };
+    // Words are slices of `s`
+    let start = last.as_ptr() as usize - s.as_ptr() as usize;
+    let end = start + last.len();
+    let inflected = recase(last, &f(&last.to_lowercase()));
+    format!("{}{}{}", &s[..start], inflected, &s[end..])
+}
+
+/// Writes `inflected` in the casing of `original`: uppercase, capitalized or lowercase.
+fn recase(original: &str, inflected: &str) -> String {
+    let mut letters = original
+        .chars()
+        .filter(|c| c.is_lowercase() || c.is_uppercase());
+    if !letters.next().is_some_and(char::is_uppercase) {
+        return inflected.to_string();
+    }
+    if letters.clone().next().is_some() && letters.all(char::is_uppercase) {
+        return inflected.to_uppercase();
+    }
+    let mut chars = inflected.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+fn replace_suffix(word: &str, suffix: &str, replacement: &str) -> Option<String> {
+    word.strip_suffix(suffix)
+        .map(|stem| format!("{}{}", stem, replacement))
+}
+
+/// True if `word` ends with `suffix`, following a character that isn't in `not`.
+fn ends_after(word: &str, suffix: &str, not: &str) -> bool {
+    word.strip_suffix(suffix)
+        .and_then(|stem| stem.chars().last())
+        .is_some_and(|c| !not.contains(c))
+}
+
+/// Replaces an irregular form at the end of `word`, where `forms` picks which form to
+/// find and which to replace it with.  Irregular forms end compound words like
+/// `salesperson`, but `man` only does after an `s`, so that `human` is regular.
+fn irregular<F>(word: &str, forms: F) -> Option<String>
+where
+    F: Fn(&(&'static str, &'static str)) -> (&'static str, &'static str),
+{
+    IRREGULARS.iter().find_map(|pair| {
+        let (from, to) = forms(pair);
+        let stem = word.strip_suffix(from)?;
+        let compound = !matches!(from, "man" | "men") || stem.ends_with('s');
+        (stem.is_empty() || compound).then(|| format!("{}{}", stem, to))
+    })
+}
+
+fn plural(word: &str) -> String {
+    if UNCOUNTABLES.contains(&word) {
+        return word.to_string();
+    }
+    if let Some(plural) =
+        irregular(word, |&(_, p)| (p, p)).or_else(|| irregular(word, |&pair| pair))
+    {
+        return plural;
+    }
+
+    match word {
+        "ox" | "oxen" => return "oxen".to_string(),
+        "mouse" | "mice" => return "mice".to_string(),
+        "louse" | "lice" => return "lice".to_string(),
+        "axis" => return "axes".to_string(),
+        "testis" => return "testes".to_string(),
+        _ => {}
+    }
+
+    let rules = [
+        ("quiz", "quizzes"),
+        ("matrix", "matrices"),
+        ("vertex", "vertices"),
+        ("index", "indices"),
+        ("sis", "ses"),
+        ("tum", "ta"),
+        ("ium", "ia"),
+        ("buffalo", "buffaloes"),
+        ("tomato", "tomatoes"),
+        ("bus", "buses"),
+        ("alias", "aliases"),
+        ("status", "statuses"),
+        ("octopus", "octopi"),
+        ("virus", "viri"),
+    ];
+    if let Some(plural) = rules
+        .iter()
+        .find_map(|(suffix, replacement)| replace_suffix(word, suffix, replacement))
+    {
+        return plural;
+    }
+
+    if ["x", "ch", "ss", "sh"].iter().any(|s| word.ends_with(s)) {
+        format!("{}es", word)
+    } else if word.ends_with("quy") || ends_after(word, "y", "aeiouy") {
+        format!("{}ies", &word[..word.len() - 1])
+    } else if ends_after(word, "fe", "f") {
+        format!("{}ves", &word[..word.len() - 2])
+    } else if word.ends_with("lf") || word.ends_with("rf") {
+        format!("{}ves", &word[..word.len() - 1])
+    } else if word.ends_with('s')
+        || matches!(word, "octopi" | "viri")
+        || word.len() > 2 && (word.ends_with("ta") || word.ends_with("ia"))
+    {
+        // Already plural
+        word.to_string()
+    } else {
+        format!("{}s", word)
+    }
+}
+
+fn singular(word: &str) -> String {
+    if UNCOUNTABLES.contains(&word) {
+        return word.to_string();
+    }
+    if let Some(singular) =
+        irregular(word, |&(s, _)| (s, s)).or_else(|| irregular(word, |&(s, p)| (p, s)))
+    {
+        return singular;
+    }
+
+    match word {
+        "oxen" => return "ox".to_string(),
+        "mice" => return "mouse".to_string(),
+        "lice" => return "louse".to_string(),
+        "axes" => return "axis".to_string(),
+        _ => {}
+    }
+
+    let rules = [
+        ("databases", "database"),
+        ("quizzes", "quiz"),
+        ("matrices", "matrix"),
+        ("vertices", "vertex"),
+        ("indices", "index"),
+        ("aliases", "alias"),
+        ("alias", "alias"),
+        ("statuses", "status"),
+        ("octopi", "octopus"),
+        ("viri", "virus"),
+        ("crises", "crisis"),
+        ("testes", "testis"),
+        ("shoes", "shoe"),
+        ("oes", "o"),
+        ("buses", "bus"),
+        ("movies", "movie"),
+        ("series", "series"),
+        ("lves", "lf"),
+        ("rves", "rf"),
+        ("tives", "tive"),
+        ("hives", "hive"),
+    ];
+    if let Some(singular) = rules
+        .iter()
+        .find_map(|(suffix, replacement)| replace_suffix(word, suffix, replacement))
+    {
+        return singular;
+    }
+
+    let sis = [
+        "analy", "ba", "diagno", "parenthe", "progno", "synop", "the",
+    ];
+    if let Some(stem) = word.strip_suffix("ses") {
+        if sis.iter().any(|s| stem.ends_with(s)) {
+            return format!("{}sis", stem);
+        }
+    }
+
+    if ["xes", "ches", "sses", "shes"]
+        .iter()
+        .any(|s| word.ends_with(s))
+    {
+        word[..word.len() - 2].to_string()
+    } else if word.ends_with("quies") || ends_after(word, "ies", "aeiouy") {
+        format!("{}y", &word[..word.len() - 3])
+    } else if ends_after(word, "ves", "f") {
+        format!("{}fe", &word[..word.len() - 3])
+    } else if word.len() > 2 && (word.ends_with("ta") || word.ends_with("ia")) {
+        format!("{}um", &word[..word.len() - 1])
+    } else if ["ss", "us", "is", "news"].iter().any(|s| word.ends_with(s)) {
+        // Already singular
+        word.to_string()
+    } else if let Some(stem) = word.strip_suffix('s') {
+        stem.to_string()
+    } else {
+        word.to_string()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Singular and plural pairs from the Rails inflector tests.
+    const PAIRS: &[(&str, &str)] = &[
+        ("search", "searches"),
+        ("switch", "switches"),
+        ("fix", "fixes"),
+        ("box", "boxes"),
+        ("process", "processes"),
+        ("address", "addresses"),
+        ("case", "cases"),
+        ("stack", "stacks"),
+        ("wish", "wishes"),
+        ("fish", "fish"),
+        ("jeans", "jeans"),
+        ("category", "categories"),
+        ("query", "queries"),
+        ("ability", "abilities"),
+        ("agency", "agencies"),
+        ("movie", "movies"),
+        ("archive", "archives"),
+        ("index", "indices"),
+        ("wife", "wives"),
+        ("safe", "saves"),
+        ("half", "halves"),
+        ("move", "moves"),
+        ("salesperson", "salespeople"),
+        ("person", "people"),
+        ("spokesman", "spokesmen"),
+        ("man", "men"),
+        ("woman", "women"),
+        ("basis", "bases"),
+        ("diagnosis", "diagnoses"),
+        ("datum", "data"),
+        ("medium", "media"),
+        ("stadium", "stadia"),
+        ("analysis", "analyses"),
+        ("node_child", "node_children"),
+        ("child", "children"),
+        ("experience", "experiences"),
+        ("day", "days"),
+        ("comment", "comments"),
+        ("foobar", "foobars"),
+        ("newsletter", "newsletters"),
+        ("old_news", "old_news"),
+        ("news", "news"),
+        ("series", "series"),
+        ("species", "species"),
+        ("quiz", "quizzes"),
+        ("perspective", "perspectives"),
+        ("ox", "oxen"),
+        ("photo", "photos"),
+        ("buffalo", "buffaloes"),
+        ("tomato", "tomatoes"),
+        ("dwarf", "dwarves"),
+        ("elf", "elves"),
+        ("information", "information"),
+        ("equipment", "equipment"),
+        ("bus", "buses"),
+        ("mouse", "mice"),
+        ("louse", "lice"),
+        ("house", "houses"),
+        ("octopus", "octopi"),
+        ("virus", "viri"),
+        ("alias", "aliases"),
+        ("portfolio", "portfolios"),
+        ("vertex", "vertices"),
+        ("matrix", "matrices"),
+        ("axis", "axes"),
+        ("testis", "testes"),
+        ("crisis", "crises"),
+        ("rice", "rice"),
+        ("shoe", "shoes"),
+        ("horse", "horses"),
+        ("prize", "prizes"),
+        ("edge", "edges"),
+        ("database", "databases"),
+        ("status", "statuses"),
+        ("zombie", "zombies"),
+    ];
+
+    #[test]
+    fn rails_pairs() {
+        for &(singular, plural) in PAIRS {
+            assert_eq!(pluralize(singular), plural, "pluralize {}", singular);
+            assert_eq!(pluralize(plural), plural, "pluralize {}", plural);
+            assert_eq!(singularize(plural), singular, "singularize {}", plural);
+            assert_eq!(singularize(singular), singular, "singularize {}", singular);
+        }
+    }
+
+    #[test]
+    fn any_case() {
+        assert_eq!(pluralize("lineItem"), "lineItems");
+        assert_eq!(pluralize("LineItem"), "LineItems");
+        assert_eq!(pluralize("LINE_ITEM"), "LINE_ITEMS");
+        assert_eq!(pluralize("line-item"), "line-items");
+        assert_eq!(pluralize("Line Item"), "Line Items");
+        assert_eq!(pluralize("user_"), "users_");
+        assert_eq!(pluralize("HTTPProxy"), "HTTPProxies");
+        assert_eq!(pluralize("item2"), "item2s");
+        assert_eq!(pluralize("human"), "humans");
+        assert_eq!(singularize("specimens"), "specimen");
+        assert_eq!(pluralize(""), "");
+        assert_eq!(pluralize("__"), "__");
+        assert_eq!(singularize("LineItems"), "LineItem");
+        assert_eq!(singularize("SALES_PEOPLE"), "SALES_PERSON");
+        assert_eq!(singularize("Mice"), "Mouse");
+    }
+
+    #[test]
+    fn table_names() {
+        assert_eq!(tableize("person"), "people");
+        assert_eq!(tableize("PrimarySpokesman"), "primary_spokesmen");
+        assert_eq!(tableize("node child"), "node_children");
+        assert_eq!(classify("primary_spokesmen"), "PrimarySpokesman");
+        assert_eq!(classify("node_children"), "NodeChild");
+        assert_eq!(classify("schema.categories"), "Category");
+        assert_eq!(foreign_key("Person"), "person_id");
+        assert_eq!(foreign_key("MyApplication::Billing::Account"), "account_id");
+        assert_eq!(humanize("_id"), "Id");
+        assert_eq!(humanize("employee_first_name"), "Employee first name");
+        assert_eq!(humanize("HTTPStatusID"), "Http status");
+    }
+
+    #[test]
+    fn ordinals() {
+        let expected = [
+            (0, "0th"),
+            (1, "1st"),
+            (2, "2nd"),
+            (3, "3rd"),
+            (4, "4th"),
+            (11, "11th"),
+            (12, "12th"),
+            (13, "13th"),
+            (21, "21st"),
+            (101, "101st"),
+            (111, "111th"),
+            (1003, "1003rd"),
+            (-1, "-1st"),
+            (-11, "-11th"),
+            (i64::MIN, "-9223372036854775808th"),
+        ];
+        for (n, ordinal) in expected {
+            assert_eq!(ordinalize(n), ordinal);
+        }
+    }
+}
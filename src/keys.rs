@@ -0,0 +1,498 @@
+//! Renaming the keys of JSON, TOML and YAML values.
+//!
+//! Every key of every object in a value is converted, including objects nested inside
+//! arrays.  Only keys are changed, never string values.
+//!
+//! This is only available with the "serde" feature.  YAML values are only supported with
+//! the "yaml" feature, which depends on `serde_yaml`.  That crate is deprecated and no
+//! longer maintained, so it is not enabled by "serde".
+//! ```
+//! use convert_case::Case;
+//! use convert_case_extras::keys::rename_keys;
+//! use serde_json::json;
+//!
+//! let mut value = json!({
+//!     "user_id": 1,
+//!     "display_name": "first_user",
+//!     "linked_accounts": [{ "account_type": "github" }],
+//! });
+//! rename_keys(&mut value, Case::Camel).unwrap();
+//! assert_eq!(value, json!({
+//!     "userId": 1,
+//!     "displayName": "first_user",
+//!     "linkedAccounts": [{ "accountType": "github" }],
+//! }));
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::string::{String, ToString};
+use std::vec::Vec;
+
+use convert_case::{Boundary, Case};
+
+use crate::{ExtraConverter, ExtraPattern};
+
+/// Converts every key in `value` into `case`, splitting keys on the default boundaries.
+///
+/// Returns an error and leaves `value` unchanged if two keys of the same object would
+/// be renamed to the same key.  See [`KeyRenamer`] for more options.
+pub fn rename_keys<V: KeyedValue>(value: &mut V, case: Case) -> Result<(), KeyCollision> {
+    KeyRenamer::new(case).rename(value)
+}
+
+/// Two keys of the same object that would be renamed to the same key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyCollision {
+    /// The location of the object, as a JSON pointer like `/users/0`.
+    pub path: String,
+    /// The original keys, in the order they appear in the object.
+    pub keys: [String; 2],
+    /// The key that both would be renamed to.
+    pub renamed: String,
+}
+
+impl fmt::Display for KeyCollision {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "keys `{}` and `{}` at `{}` would both be renamed to `{}`",
+            self.keys[0], self.keys[1], self.path, self.renamed
+        )
+    }
+}
+
+impl std::error::Error for KeyCollision {}
+
+/// Renames the keys of values, with control over how keys are converted, which keys are
+/// renamed, and how deeply.
+///
+/// ```
+/// use convert_case::Case;
+/// use convert_case_extras::keys::KeyRenamer;
+/// use serde_json::json;
+///
+/// let renamer = KeyRenamer::new(Case::Snake)
+///     .from_case(Case::Camel)
+///     .exclude("metaData")
+///     .max_depth(2);
+///
+/// let mut value = json!({
+///     "userName": "first",
+///     "metaData": { "createdBy": "admin" },
+///     "homeAddress": { "streetName": "Main", "geoPoint": { "latLng": [0, 0] } },
+/// });
+/// renamer.rename(&mut value).unwrap();
+/// assert_eq!(value, json!({
+///     "user_name": "first",
+///     "metaData": { "createdBy": "admin" },
+///     "home_address": { "street_name": "Main", "geo_point": { "latLng": [0, 0] } },
+/// }));
+///
+/// let mut value = json!({ "user_id": 1, "userId": 2 });
+/// let err = KeyRenamer::new(Case::Camel).rename(&mut value).unwrap_err();
+/// assert_eq!(err.renamed, "userId");
+/// assert_eq!(value, json!({ "user_id": 1, "userId": 2 }));
+/// ```
+pub struct KeyRenamer {
+    converter: ExtraConverter,
+    exclude: Vec<String>,
+    max_depth: Option<usize>,
+}
+
+impl KeyRenamer {
+    /// Creates a renamer that converts keys into `case`, splitting them on the default
+    /// boundaries.
+    pub fn new(case: Case) -> Self {
+        Self::from_converter(ExtraConverter::new().to_case(case))
+    }
+
+    /// Creates a renamer that converts keys with `converter`.
+    pub fn from_converter(converter: ExtraConverter) -> Self {
+        KeyRenamer {
+            converter,
+            exclude: Vec::new(),
+            max_depth: None,
+        }
+    }
+
+    /// Splits keys on the boundaries of `case`.
+    pub fn from_case(mut self, case: Case) -> Self {
+        self.converter = self.converter.from_case(case);
+        self
+    }
+
+    /// Splits keys on the given boundaries.
+    pub fn set_boundaries(mut self, boundaries: &[Boundary]) -> Self {
+        self.converter = self.converter.set_boundaries(boundaries);
+        self
+    }
+
+    /// Mutates the words of each key with `pattern`, for example an
+    /// [`AcronymPattern`](crate::pattern::AcronymPattern).
+    pub fn set_pattern<P: ExtraPattern + 'static>(mut self, pattern: P) -> Self {
+        self.converter = self.converter.set_pattern(pattern);
+        self
+    }
+
+    /// Leaves `key` and everything in its value as they are, wherever it appears.
+    pub fn exclude(mut self, key: &str) -> Self {
+        self.exclude.push(key.to_string());
+        self
+    }
+
+    /// Only renames the keys of objects nested inside fewer than `depth` other objects.
+    /// A depth of `1` renames only the keys of the outermost object.  Arrays do not
+    /// count towards the depth.
+    pub fn max_depth(mut self, depth: usize) -> Self {
+        self.max_depth = Some(depth);
+        self
+    }
+
+    /// Renames the keys of `value`.
+    ///
+    /// Returns an error and leaves `value` unchanged if two keys of the same object would
+    /// be renamed to the same key.
+    pub fn rename<V: KeyedValue>(&self, value: &mut V) -> Result<(), KeyCollision> {
+        value.check_keys(self, 0, &mut String::new())?;
+        value.rename_keys(self, 0);
+        Ok(())
+    }
+
+    /// The new name of `key`.
+    pub fn rename_key(&self, key: &str) -> String {
+        if self.is_excluded(key) {
+            key.to_string()
+        } else {
+            self.converter.convert(key)
+        }
+    }
+
+    fn is_excluded(&self, key: &str) -> bool {
+        self.exclude.iter().any(|excluded| excluded == key)
+    }
+
+    fn renames_at(&self, depth: usize) -> bool {
+        self.max_depth.is_none_or(|max| depth < max)
+    }
+
+    /// Finds two of `keys` that would be renamed to the same key.
+    fn check<'k>(
+        &self,
+        keys: impl IntoIterator<Item = &'k str>,
+        path: &str,
+    ) -> Result<(), KeyCollision> {
+        let mut renamed_keys: BTreeMap<String, &str> = BTreeMap::new();
+        for key in keys {
+            let renamed = self.rename_key(key);
+            if let Some(other) = renamed_keys.insert(renamed.clone(), key) {
+                return Err(KeyCollision {
+                    path: path.to_string(),
+                    keys: [other.to_string(), key.to_string()],
+                    renamed,
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+mod sealed {
+    pub trait Sealed {}
+    impl Sealed for serde_json::Value {}
+    impl Sealed for toml::Value {}
+    #[cfg(feature = "yaml")]
+    impl Sealed for serde_yaml::Value {}
+}
+
+/// A value made of nested objects and arrays whose keys can be renamed.  This is
+/// implemented for [`serde_json::Value`] and [`toml::Value`], and for
+/// `serde_yaml::Value` with the "yaml" feature.
+///
+/// Only string keys of YAML mappings are renamed.
+pub trait KeyedValue: sealed::Sealed {
+    #[doc(hidden)]
+    fn check_keys(
+        &self,
+        renamer: &KeyRenamer,
+        depth: usize,
+        path: &mut String,
+    ) -> Result<(), KeyCollision>;
+
+    #[doc(hidden)]
+    fn rename_keys(&mut self, renamer: &KeyRenamer, depth: usize);
+}
+
+/// Appends `segment` to a JSON pointer, calls `f`, then removes it again.
+fn nested<T>(path: &mut String, segment: &str, f: impl FnOnce(&mut String) -> T) -> T {
+    let len = path.len();
+    path.push('/');
+    path.push_str(&segment.replace('~', "~0").replace('/', "~1"));
+    let result = f(path);
+    path.truncate(len);
+    result
+}
+
+/// Checks the keys of an object, then the values of the keys that aren't excluded.  Keys
+/// that aren't strings are `None`, and are neither renamed nor part of the path.
+fn check_object<'v, V: KeyedValue + 'v>(
+    entries: impl IntoIterator<Item = (Option<&'v str>, &'v V)>,
+    renamer: &KeyRenamer,
+    depth: usize,
+    path: &mut String,
+) -> Result<(), KeyCollision> {
+    let entries: Vec<_> = entries.into_iter().collect();
+    renamer.check(entries.iter().filter_map(|&(key, _)| key), path)?;
+    entries.into_iter().try_for_each(|(key, value)| match key {
+        Some(key) if renamer.is_excluded(key) => Ok(()),
+        Some(key) => nested(path, key, |path| value.check_keys(renamer, depth + 1, path)),
+        None => value.check_keys(renamer, depth + 1, path),
+    })
+}
+
+/// Checks the values of an array, which are at the same depth as the array.
+fn check_array<'v, V: KeyedValue + 'v>(
+    values: impl IntoIterator<Item = &'v V>,
+    renamer: &KeyRenamer,
+    depth: usize,
+    path: &mut String,
+) -> Result<(), KeyCollision> {
+    values.into_iter().enumerate().try_for_each(|(i, value)| {
+        nested(path, &i.to_string(), |path| {
+            value.check_keys(renamer, depth, path)
+        })
+    })
+}
+
+/// Renames the keys inside `value`, unless `key` is excluded, and returns the new key.
+fn rename_entry<V: KeyedValue>(
+    key: String,
+    value: &mut V,
+    renamer: &KeyRenamer,
+    depth: usize,
+) -> String {
+    if renamer.is_excluded(&key) {
+        return key;
+    }
+    value.rename_keys(renamer, depth + 1);
+    renamer.rename_key(&key)
+}
+
+// JSON objects and TOML tables are both maps from strings to values.
+macro_rules! impl_keyed_value {
+    ($module:ident, $object:ident, $array:ident) => {
+        impl KeyedValue for $module::Value {
+            fn check_keys(
+                &self,
+                renamer: &KeyRenamer,
+                depth: usize,
+                path: &mut String,
+            ) -> Result<(), KeyCollision> {
+                use $module::Value;
+                match self {
+                    Value::$object(map) if renamer.renames_at(depth) => check_object(
+                        map.iter().map(|(key, value)| (Some(key.as_str()), value)),
+                        renamer,
+                        depth,
+                        path,
+                    ),
+                    Value::$array(values) => check_array(values, renamer, depth, path),
+                    _ => Ok(()),
+                }
+            }
+
+            fn rename_keys(&mut self, renamer: &KeyRenamer, depth: usize) {
+                use $module::Value;
+                match self {
+                    Value::$object(map) if renamer.renames_at(depth) => {
+                        *map = std::mem::take(map)
+                            .into_iter()
+                            .map(|(key, mut value)| {
+                                (rename_entry(key, &mut value, renamer, depth), value)
+                            })
+                            .collect();
+                    }
+                    Value::$array(values) => {
+                        for value in values {
+                            value.rename_keys(renamer, depth);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+    };
+}
+
+impl_keyed_value!(serde_json, Object, Array);
+impl_keyed_value!(toml, Table, Array);
+
+#[cfg(feature = "yaml")]
+impl KeyedValue for serde_yaml::Value {
+    fn check_keys(
+        &self,
+        renamer: &KeyRenamer,
+        depth: usize,
+        path: &mut String,
+    ) -> Result<(), KeyCollision> {
+        use serde_yaml::Value;
+        match self {
+            Value::Mapping(mapping) if renamer.renames_at(depth) => check_object(
+                mapping.iter().map(|(key, value)| (key.as_str(), value)),
+                renamer,
+                depth,
+                path,
+            ),
+            Value::Sequence(values) => check_array(values, renamer, depth, path),
+            Value::Tagged(tagged) => tagged.value.check_keys(renamer, depth, path),
+            _ => Ok(()),
+        }
+    }
+
+    fn rename_keys(&mut self, renamer: &KeyRenamer, depth: usize) {
+        use serde_yaml::Value;
+        match self {
+            Value::Mapping(mapping) if renamer.renames_at(depth) => {
+                *mapping = std::mem::take(mapping)
+                    .into_iter()
+                    .map(|(key, mut value)| match key {
+                        Value::String(key) => {
+                            let key = rename_entry(key, &mut value, renamer, depth);
+                            (Value::String(key), value)
+                        }
+                        key => {
+                            value.rename_keys(renamer, depth + 1);
+                            (key, value)
+                        }
+                    })
+                    .collect();
+            }
+            Value::Sequence(values) => {
+                for value in values {
+                    value.rename_keys(renamer, depth);
+                }
+            }
+            Value::Tagged(tagged) => tagged.value.rename_keys(renamer, depth),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use serde_json::json;
+
+    #[test]
+    fn nested_json() {
+        let mut value = json!({
+            "pageInfo": { "hasNextPage": true },
+            "edges": [[{ "nodeId": "a_b" }], "stringValue"],
+        });
+        rename_keys(&mut value, Case::Snake).unwrap();
+        assert_eq!(
+            value,
+            json!({
+                "page_info": { "has_next_page": true },
+                "edges": [[{ "node_id": "a_b" }], "stringValue"],
+            })
+        );
+    }
+
+    #[test]
+    fn depth_limit() {
+        let mut value = json!([{ "a_b": { "c_d": { "e_f": 1 } } }]);
+        KeyRenamer::new(Case::Kebab)
+            .max_depth(1)
+            .rename(&mut value)
+            .unwrap();
+        assert_eq!(value, json!([{ "a-b": { "c_d": { "e_f": 1 } } }]));
+
+        let mut value = json!({ "a_b": 1 });
+        KeyRenamer::new(Case::Kebab)
+            .max_depth(0)
+            .rename(&mut value)
+            .unwrap();
+        assert_eq!(value, json!({ "a_b": 1 }));
+    }
+
+    #[test]
+    fn collisions() {
+        let mut value = json!({ "list": [{}, { "outer": { "my-key": 1, "MY_KEY": 2 } }] });
+        let err = rename_keys(&mut value, Case::Snake).unwrap_err();
+        assert_eq!(
+            err,
+            KeyCollision {
+                path: "/list/1/outer".to_string(),
+                keys: ["MY_KEY".to_string(), "my-key".to_string()],
+                renamed: "my_key".to_string(),
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "keys `MY_KEY` and `my-key` at `/list/1/outer` would both be renamed to `my_key`"
+        );
+
+        // An excluded key keeps its name, and can collide with a renamed key
+        let mut value = json!({ "my_key": 1, "MyKey": 2 });
+        let renamer = KeyRenamer::new(Case::Snake).exclude("my_key");
+        assert!(renamer.rename(&mut value).is_err());
+
+        // Collisions below the depth limit don't matter
+        let mut value = json!({ "outer": { "my_key": 1, "myKey": 2 } });
+        let renamer = KeyRenamer::new(Case::Snake).max_depth(1);
+        assert!(renamer.rename(&mut value).is_ok());
+    }
+
+    #[test]
+    fn toml_tables() {
+        let mut value: toml::Value = toml::from_str(
+            r#"
+            packageName = "crate"
+            [[targetList]]
+            binName = "tool"
+            "#,
+        )
+        .unwrap();
+        rename_keys(&mut value, Case::Kebab).unwrap();
+        assert_eq!(
+            value,
+            toml::from_str::<toml::Value>(
+                r#"
+                package-name = "crate"
+                [[target-list]]
+                bin-name = "tool"
+                "#
+            )
+            .unwrap()
+        );
+    }
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn yaml_mappings() {
+        let mut value: serde_yaml::Value = serde_yaml::from_str(
+            "
+            first_key: 1
+            2: { nested_key: true }
+            tagged: !Tag { inner_key: [] }
+            list: [{ item_key: x }]
+            ",
+        )
+        .unwrap();
+        rename_keys(&mut value, Case::Camel).unwrap();
+        assert_eq!(
+            value,
+            serde_yaml::from_str::<serde_yaml::Value>(
+                "
+                firstKey: 1
+                2: { nestedKey: true }
+                tagged: !Tag { innerKey: [] }
+                list: [{ itemKey: x }]
+                "
+            )
+            .unwrap()
+        );
+    }
+}
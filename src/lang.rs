@@ -0,0 +1,780 @@
+//! Identifiers for programming languages.
+//!
+//! Converts arbitrary names into identifiers that follow the naming conventions of a
+//! language, and that the language will accept: characters that cannot appear in an
+//! identifier are treated as word separators, a leading digit is prefixed with an
+//! underscore (or an `X` in Go), and reserved keywords are escaped the way the language
+//! allows.
+//!
+//! ```
+//! use convert_case_extras::lang::{Item, Language};
+//!
+//! assert_eq!(Language::Rust.identifier("user name", Item::Type), "UserName");
+//! assert_eq!(Language::Rust.identifier("Type", Item::Field), "r#type");
+//! assert_eq!(Language::Python.identifier("class", Item::Field), "class_");
+//! assert_eq!(Language::TypeScript.identifier("delete", Item::Function), "delete_");
+//! assert_eq!(Language::Go.identifier("2nd place!", Item::Function), "X2ndPlace");
+//! ```
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use convert_case::{Boundary, Case, Casing};
+
+/// A programming language with its own identifier rules and naming conventions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Language {
+    /// Keywords are escaped as raw identifiers, like `r#type`.  The keywords that can't
+    /// be raw identifiers, like `self`, are suffixed with an underscore instead.
+    Rust,
+
+    /// Keywords, including the soft keywords `match`, `case` and `type`, are suffixed with
+    /// an underscore, like `class_`.
+    Python,
+
+    /// Every item is exported, so every item is in pascal case.  An identifier that would
+    /// start with a digit is prefixed with `X` rather than an underscore, which would make
+    /// it unexported.  Go's keywords are all lowercase, so identifiers never need
+    /// escaping.
+    Go,
+
+    /// Identifiers may also contain `$`.  Keywords are suffixed with an underscore, like
+    /// `class_`.
+    Java,
+
+    /// Identifiers may also contain `$`.  Keywords are suffixed with an underscore, like
+    /// `delete_`.
+    JavaScript,
+
+    /// Identifiers may also contain `$`.  Keywords are suffixed with an underscore, like
+    /// `delete_`.
+    TypeScript,
+
+    /// Public members are in pascal case.  Keywords are escaped as verbatim identifiers,
+    /// like `@class`.
+    CSharp,
+
+    /// Every item is in snake case.  Keywords are matched regardless of case and escaped
+    /// as quoted identifiers, like `"order"`.  Identifiers may contain `$` after the first
+    /// character.
+    Sql,
+
+    /// Keywords are escaped with backticks, like `` `object` ``.
+    Kotlin,
+}
+
+/// The kind of item an identifier names.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Item {
+    /// A type, class, struct, enum or interface.
+    Type,
+    /// A function or method.
+    Function,
+    /// A constant or static value.
+    Constant,
+    /// A field, property, variable or parameter.
+    Field,
+}
+
+/// Boundaries for user supplied names.  Digits stay attached to the letters before them,
+/// so that `2nd` and `sha256` remain whole words.
+const BOUNDARIES: [Boundary; 6] = [
+    Boundary::Underscore,
+    Boundary::Hyphen,
+    Boundary::Space,
+    Boundary::LowerUpper,
+    Boundary::DigitUpper,
+    Boundary::Acronym,
+];
+
+const RUST: &[&str] = &[
+    "_", "abstract", "as", "async", "await", "become", "box", "break", "const", "continue",
+    "crate", "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if",
+    "impl", "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub",
+    "ref", "return", "self", "Self", "static", "struct", "super", "trait", "true", "try", "type",
+    "typeof", "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+/// Rust keywords that can't be written as raw identifiers.
+const RUST_NOT_RAW: &[&str] = &["_", "crate", "self", "Self", "super"];
+
+const PYTHON: &[&str] = &[
+    "False", "None", "True", "and", "as", "assert", "async", "await", "break", "case", "class",
+    "continue", "def", "del", "elif", "else", "except", "finally", "for", "from", "global", "if",
+    "import", "in", "is", "lambda", "match", "nonlocal", "not", "or", "pass", "raise", "return",
+    "try", "type", "while", "with", "yield",
+];
+
+const GO: &[&str] = &[
+    "break",
+    "case",
+    "chan",
+    "const",
+    "continue",
+    "default",
+    "defer",
+    "else",
+    "fallthrough",
+    "for",
+    "func",
+    "go",
+    "goto",
+    "if",
+    "import",
+    "interface",
+    "map",
+    "package",
+    "range",
+    "return",
+    "select",
+    "struct",
+    "switch",
+    "type",
+    "var",
+];
+
+const JAVA: &[&str] = &[
+    "_",
+    "abstract",
+    "assert",
+    "boolean",
+    "break",
+    "byte",
+    "case",
+    "catch",
+    "char",
+    "class",
+    "const",
+    "continue",
+    "default",
+    "do",
+    "double",
+    "else",
+    "enum",
+    "extends",
+    "false",
+    "final",
+    "finally",
+    "float",
+    "for",
+    "goto",
+    "if",
+    "implements",
+    "import",
+    "instanceof",
+    "int",
+    "interface",
+    "long",
+    "native",
+    "new",
+    "null",
+    "package",
+    "private",
+    "protected",
+    "public",
+    "record",
+    "return",
+    "short",
+    "static",
+    "strictfp",
+    "super",
+    "switch",
+    "synchronized",
+    "this",
+    "throw",
+    "throws",
+    "transient",
+    "true",
+    "try",
+    "var",
+    "void",
+    "volatile",
+    "while",
+    "yield",
+];
+
+/// The reserved words of JavaScript, which TypeScript shares.
+const JAVASCRIPT: &[&str] = &[
+    "await",
+    "break",
+    "case",
+    "catch",
+    "class",
+    "const",
+    "continue",
+    "debugger",
+    "default",
+    "delete",
+    "do",
+    "else",
+    "enum",
+    "export",
+    "extends",
+    "false",
+    "finally",
+    "for",
+    "function",
+    "if",
+    "implements",
+    "import",
+    "in",
+    "instanceof",
+    "interface",
+    "let",
+    "new",
+    "null",
+    "package",
+    "private",
+    "protected",
+    "public",
+    "return",
+    "static",
+    "super",
+    "switch",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typeof",
+    "var",
+    "void",
+    "while",
+    "with",
+    "yield",
+];
+
+const CSHARP: &[&str] = &[
+    "abstract",
+    "as",
+    "base",
+    "bool",
+    "break",
+    "byte",
+    "case",
+    "catch",
+    "char",
+    "checked",
+    "class",
+    "const",
+    "continue",
+    "decimal",
+    "default",
+    "delegate",
+    "do",
+    "double",
+    "else",
+    "enum",
+    "event",
+    "explicit",
+    "extern",
+    "false",
+    "finally",
+    "fixed",
+    "float",
+    "for",
+    "foreach",
+    "goto",
+    "if",
+    "implicit",
+    "in",
+    "int",
+    "interface",
+    "internal",
+    "is",
+    "lock",
+    "long",
+    "namespace",
+    "new",
+    "null",
+    "object",
+    "operator",
+    "out",
+    "override",
+    "params",
+    "private",
+    "protected",
+    "public",
+    "readonly",
+    "ref",
+    "return",
+    "sbyte",
+    "sealed",
+    "short",
+    "sizeof",
+    "stackalloc",
+    "static",
+    "string",
+    "struct",
+    "switch",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typeof",
+    "uint",
+    "ulong",
+    "unchecked",
+    "unsafe",
+    "ushort",
+    "using",
+    "virtual",
+    "void",
+    "volatile",
+    "while",
+];
+
+const SQL: &[&str] = &[
+    "all",
+    "alter",
+    "and",
+    "any",
+    "as",
+    "asc",
+    "authorization",
+    "between",
+    "both",
+    "by",
+    "case",
+    "cast",
+    "check",
+    "collate",
+    "column",
+    "constraint",
+    "create",
+    "cross",
+    "current_date",
+    "current_time",
+    "current_timestamp",
+    "current_user",
+    "default",
+    "delete",
+    "desc",
+    "distinct",
+    "drop",
+    "else",
+    "end",
+    "except",
+    "exists",
+    "false",
+    "fetch",
+    "for",
+    "foreign",
+    "from",
+    "full",
+    "grant",
+    "group",
+    "having",
+    "in",
+    "inner",
+    "insert",
+    "intersect",
+    "into",
+    "is",
+    "join",
+    "leading",
+    "left",
+    "like",
+    "limit",
+    "natural",
+    "not",
+    "null",
+    "offset",
+    "on",
+    "only",
+    "or",
+    "order",
+    "outer",
+    "primary",
+    "references",
+    "returning",
+    "right",
+    "select",
+    "session_user",
+    "some",
+    "table",
+    "then",
+    "to",
+    "trailing",
+    "true",
+    "union",
+    "unique",
+    "update",
+    "user",
+    "using",
+    "values",
+    "when",
+    "where",
+    "window",
+    "with",
+];
+
+const KOTLIN: &[&str] = &[
+    "as",
+    "break",
+    "class",
+    "continue",
+    "do",
+    "else",
+    "false",
+    "for",
+    "fun",
+    "if",
+    "in",
+    "interface",
+    "is",
+    "null",
+    "object",
+    "package",
+    "return",
+    "super",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typealias",
+    "typeof",
+    "val",
+    "var",
+    "when",
+    "while",
+];
+
+impl Language {
+    /// Looks up a language by its name or common abbreviation, like `"rust"`, `"js"`
+    /// or `"csharp"`.
+    /// ```
+    /// use convert_case_extras::lang::Language;
+    ///
+    /// assert_eq!(Language::by_name("py"), Some(Language::Python));
+    /// assert_eq!(Language::by_name("c#"), Some(Language::CSharp));
+    /// assert_eq!(Language::by_name("cobol"), None);
+    /// ```
+    pub fn by_name(name: &str) -> Option<Self> {
+        match name {
+            "rust" | "rs" => Some(Language::Rust),
+            "python" | "py" => Some(Language::Python),
+            "go" => Some(Language::Go),
+            "java" => Some(Language::Java),
+            "javascript" | "js" => Some(Language::JavaScript),
+            "typescript" | "ts" => Some(Language::TypeScript),
+            "csharp" | "cs" | "c#" => Some(Language::CSharp),
+            "sql" => Some(Language::Sql),
+            "kotlin" | "kt" => Some(Language::Kotlin),
+            _ => None,
+        }
+    }
+
+    /// Guesses the language of a file from its extension, without the leading dot.
+    /// ```
+    /// use convert_case_extras::lang::Language;
+    ///
+    /// assert_eq!(Language::from_extension("rs"), Some(Language::Rust));
+    /// assert_eq!(Language::from_extension("mjs"), Some(Language::JavaScript));
+    /// assert_eq!(Language::from_extension("txt"), None);
+    /// ```
+    pub fn from_extension(extension: &str) -> Option<Self> {
+        match extension {
+            "rs" => Some(Language::Rust),
+            "py" | "pyi" => Some(Language::Python),
+            "go" => Some(Language::Go),
+            "java" => Some(Language::Java),
+            "js" | "mjs" | "cjs" | "jsx" => Some(Language::JavaScript),
+            "ts" | "mts" | "cts" | "tsx" => Some(Language::TypeScript),
+            "cs" => Some(Language::CSharp),
+            "sql" => Some(Language::Sql),
+            "kt" | "kts" => Some(Language::Kotlin),
+            _ => None,
+        }
+    }
+
+    /// The idiomatic case for an item in this language.
+    /// ```
+    /// use convert_case::Case;
+    /// use convert_case_extras::lang::{Item, Language};
+    ///
+    /// assert_eq!(Language::Java.case(Item::Function), Case::Camel);
+    /// assert_eq!(Language::Kotlin.case(Item::Constant), Case::Constant);
+    /// assert_eq!(Language::CSharp.case(Item::Field), Case::Pascal);
+    /// ```
+    pub const fn case(self, item: Item) -> Case<'static> {
+        use Language::*;
+        match (self, item) {
+            (Sql, _) => Case::Snake,
+            (Go | CSharp, _) | (_, Item::Type) => Case::Pascal,
+            (_, Item::Constant) => Case::Constant,
+            (Rust | Python, _) => Case::Snake,
+            (Java | JavaScript | TypeScript | Kotlin, _) => Case::Camel,
+        }
+    }
+
+    /// The reserved keywords of this language that can't be used as plain identifiers.
+    pub const fn keywords(self) -> &'static [&'static str] {
+        match self {
+            Language::Rust => RUST,
+            Language::Python => PYTHON,
+            Language::Go => GO,
+            Language::Java => JAVA,
+            Language::JavaScript | Language::TypeScript => JAVASCRIPT,
+            Language::CSharp => CSHARP,
+            Language::Sql => SQL,
+            Language::Kotlin => KOTLIN,
+        }
+    }
+
+    /// Returns true if `s` is a reserved keyword.  Only SQL keywords are matched
+    /// regardless of case.
+    pub fn is_keyword(self, s: &str) -> bool {
+        match self {
+            Language::Sql => SQL.iter().any(|keyword| keyword.eq_ignore_ascii_case(s)),
+            _ => self.keywords().contains(&s),
+        }
+    }
+
+    /// Converts `s` into an identifier in the idiomatic case for `item`.
+    ///
+    /// Characters that can't appear in an identifier separate words, the result is made
+    /// valid with [`sanitize`](Language::sanitize), and keywords are escaped with
+    /// [`escape`](Language::escape).  The result is always [valid](Language::is_valid).
+    /// ```
+    /// use convert_case_extras::lang::{Item, Language};
+    ///
+    /// assert_eq!(Language::Sql.identifier("Order Date", Item::Field), "order_date");
+    /// assert_eq!(Language::Sql.identifier("Order", Item::Type), "\"order\"");
+    /// assert_eq!(Language::Kotlin.identifier("object", Item::Field), "`object`");
+    /// assert_eq!(Language::Java.identifier("e-mail@work", Item::Constant), "E_MAIL_WORK");
+    /// ```
+    pub fn identifier(self, s: &str, item: Item) -> String {
+        let separated: String = s
+            .chars()
+            .map(|c| if self.is_part(c) { c } else { ' ' })
+            .collect();
+        let separated = separated.split_whitespace().collect::<Vec<_>>().join(" ");
+        let cased = separated
+            .with_boundaries(&BOUNDARIES)
+            .to_case(self.case(item));
+        self.escape(&self.sanitize(&cased))
+    }
+
+    /// Removes the characters that can't appear in an identifier, and prefixes an
+    /// underscore if the result would not start with a letter or underscore.  Go
+    /// identifiers are prefixed with `X` instead, so that they stay exported.  The case of
+    /// `s` is left as it is, and keywords are not escaped.
+    /// ```
+    /// use convert_case_extras::lang::Language;
+    ///
+    /// assert_eq!(Language::Python.sanitize("max-value!"), "maxvalue");
+    /// assert_eq!(Language::Python.sanitize("3d_model"), "_3d_model");
+    /// assert_eq!(Language::Python.sanitize("???"), "_");
+    /// assert_eq!(Language::TypeScript.sanitize("$price"), "$price");
+    /// assert_eq!(Language::Go.sanitize("2FA"), "X2FA");
+    /// ```
+    pub fn sanitize(self, s: &str) -> String {
+        let mut ident: String = s.chars().filter(|&c| self.is_part(c)).collect();
+        if !ident.starts_with(|c| self.is_start(c)) {
+            ident.insert(0, if self == Language::Go { 'X' } else { '_' });
+        }
+        ident
+    }
+
+    /// Escapes `ident` if it is a keyword, and otherwise returns it unchanged.
+    /// ```
+    /// use convert_case_extras::lang::Language;
+    ///
+    /// assert_eq!(Language::Rust.escape("match"), "r#match");
+    /// assert_eq!(Language::Rust.escape("self"), "self_");
+    /// assert_eq!(Language::Python.escape("type"), "type_");
+    /// assert_eq!(Language::Python.escape("kind"), "kind");
+    /// ```
+    pub fn escape(self, ident: &str) -> String {
+        if !self.is_keyword(ident) {
+            return ident.to_string();
+        }
+        match self {
+            Language::Rust if !RUST_NOT_RAW.contains(&ident) => format!("r#{}", ident),
+            Language::CSharp => format!("@{}", ident),
+            Language::Sql => format!("\"{}\"", ident),
+            Language::Kotlin => format!("`{}`", ident),
+            _ => format!("{}_", ident),
+        }
+    }
+
+    /// Returns true if `s` can be used as an identifier, either because it is a plain
+    /// identifier that is not a keyword, or because it is escaped.
+    /// ```
+    /// use convert_case_extras::lang::Language;
+    ///
+    /// assert!(Language::Rust.is_valid("r#type"));
+    /// assert!(Language::Rust.is_valid("user_id"));
+    /// assert!(!Language::Rust.is_valid("type"));
+    /// assert!(!Language::Rust.is_valid("r#self"));
+    /// assert!(!Language::Go.is_valid("2fa"));
+    /// ```
+    pub fn is_valid(self, s: &str) -> bool {
+        match self.unescape(s) {
+            Some(inner) => match self {
+                Language::Rust => self.is_plain(inner) && !RUST_NOT_RAW.contains(&inner),
+                Language::CSharp => self.is_plain(inner),
+                _ => !inner.is_empty(),
+            },
+            None => self.is_plain(s) && !self.is_keyword(s),
+        }
+    }
+
+    /// The identifier inside an escaped identifier, if `s` is escaped.
+    fn unescape(self, s: &str) -> Option<&str> {
+        match self {
+            Language::Rust => s.strip_prefix("r#"),
+            Language::CSharp => s.strip_prefix('@'),
+            Language::Sql => s
+                .strip_prefix('"')?
+                .strip_suffix('"')
+                .filter(|inner| !inner.contains('"')),
+            Language::Kotlin => s
+                .strip_prefix('`')?
+                .strip_suffix('`')
+                .filter(|inner| !inner.contains(['`', '\n'])),
+            _ => None,
+        }
+    }
+
+    fn is_plain(self, s: &str) -> bool {
+        s.starts_with(|c| self.is_start(c)) && s.chars().all(|c| self.is_part(c))
+    }
+
+    /// True if `c` can start an identifier.
+    pub(crate) fn is_start(self, c: char) -> bool {
+        c.is_alphabetic()
+            || c == '_'
+            || (c == '$'
+                && matches!(
+                    self,
+                    Language::Java | Language::JavaScript | Language::TypeScript
+                ))
+    }
+
+    /// True if `c` can appear anywhere in an identifier.
+    pub(crate) fn is_part(self, c: char) -> bool {
+        self.is_start(c) || c.is_ascii_digit() || (c == '$' && self == Language::Sql)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const LANGUAGES: [Language; 9] = [
+        Language::Rust,
+        Language::Python,
+        Language::Go,
+        Language::Java,
+        Language::JavaScript,
+        Language::TypeScript,
+        Language::CSharp,
+        Language::Sql,
+        Language::Kotlin,
+    ];
+
+    const ITEMS: [Item; 4] = [Item::Type, Item::Function, Item::Constant, Item::Field];
+
+    #[test]
+    fn idiomatic_cases() {
+        let expected = [
+            (Language::Rust, ["UserId", "user_id", "USER_ID", "user_id"]),
+            (
+                Language::Python,
+                ["UserId", "user_id", "USER_ID", "user_id"],
+            ),
+            (Language::Go, ["UserId", "UserId", "UserId", "UserId"]),
+            (Language::Java, ["UserId", "userId", "USER_ID", "userId"]),
+            (
+                Language::JavaScript,
+                ["UserId", "userId", "USER_ID", "userId"],
+            ),
+            (
+                Language::TypeScript,
+                ["UserId", "userId", "USER_ID", "userId"],
+            ),
+            (Language::CSharp, ["UserId", "UserId", "UserId", "UserId"]),
+            (Language::Sql, ["user_id", "user_id", "user_id", "user_id"]),
+            (Language::Kotlin, ["UserId", "userId", "USER_ID", "userId"]),
+        ];
+        for (language, names) in expected {
+            for (item, name) in ITEMS.into_iter().zip(names) {
+                assert_eq!(language.identifier("user id", item), name);
+            }
+        }
+    }
+
+    #[test]
+    fn escaped_keywords() {
+        let expected = [
+            (Language::Rust, "type", "r#type"),
+            (Language::Python, "type", "type_"),
+            (Language::Java, "class", "class_"),
+            (Language::JavaScript, "delete", "delete_"),
+            (Language::TypeScript, "class", "class_"),
+            (Language::CSharp, "class", "@class"),
+            (Language::Sql, "select", "\"select\""),
+            (Language::Kotlin, "class", "`class`"),
+        ];
+        for (language, keyword, escaped) in expected {
+            assert_eq!(language.escape(keyword), escaped);
+        }
+    }
+
+    #[test]
+    fn keyword_in_other_case() {
+        assert_eq!(Language::Rust.identifier("self", Item::Type), "Self_");
+        assert_eq!(Language::Python.identifier("none", Item::Type), "None_");
+        assert_eq!(Language::Sql.escape("SELECT"), "\"SELECT\"");
+        assert_eq!(Language::Java.identifier("class", Item::Type), "Class");
+    }
+
+    #[test]
+    fn illegal_characters() {
+        assert_eq!(
+            Language::Rust.identifier("user's e-mail (work)", Item::Field),
+            "user_s_e_mail_work"
+        );
+        assert_eq!(
+            Language::Java.identifier("price in $", Item::Field),
+            "priceIn$"
+        );
+        assert_eq!(
+            Language::Python.identifier("café crème", Item::Type),
+            "CaféCrème"
+        );
+        assert_eq!(
+            Language::Go.identifier("sha256Hash", Item::Type),
+            "Sha256Hash"
+        );
+    }
+
+    #[test]
+    fn leading_digits() {
+        assert_eq!(
+            Language::Rust.identifier("3d model", Item::Type),
+            "_3dModel"
+        );
+        assert_eq!(Language::Rust.identifier("42", Item::Constant), "_42");
+        assert_eq!(Language::Sql.identifier("$total", Item::Field), "_$total");
+        assert_eq!(Language::Go.identifier("3d model", Item::Field), "X3dModel");
+        assert_eq!(Language::Go.identifier("!!!", Item::Function), "X");
+    }
+
+    #[test]
+    fn always_valid() {
+        let inputs = [
+            "", "_", "self", "type", "class", "Order", "2fa", "a b", "!!!", "$",
+        ];
+        for language in LANGUAGES {
+            for item in ITEMS {
+                for input in inputs {
+                    let ident = language.identifier(input, item);
+                    assert!(language.is_valid(&ident), "{:?}: {}", language, ident);
+                }
+            }
+        }
+    }
+}
@@ -9,12 +9,153 @@
 //!     "tOGGLE cASE wORD",
 //! )
 //! ```
+//!
+//! This crate is `no_std` and only requires `alloc`.  The "std" feature is enabled by
+//! default, and is required for the random cases that use the thread local generator
+//! ([`case::RANDOM`] and [`case::PSEUDO_RANDOM`]), and for the "serde" and "cli"
+//! features.  Without it, the random patterns can still be used with any generator
+//! through [`pattern::RandomPattern`].
+//!
+//! The "serde" feature covers JSON and TOML.  YAML support in the `keys` module is
+//! split into its own "yaml" feature, which enables "serde", so that `serde_yaml` is
+//! only pulled in when it is used.
+//!
+//! The "unicode-segmentation" feature is enabled by default, and is required for the
+//! grapheme cases ([`case::GRAPHEME_TOGGLE`] and [`case::GRAPHEME_ALTERNATING`]), and
+//! for [`convert_into`] and [`CaseWriter`].
+
+#![cfg_attr(not(test), no_std)]
 
+extern crate alloc;
+#[cfg(all(feature = "std", not(test)))]
+extern crate std;
+
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 use convert_case::{Boundary, Case, Pattern};
 
+pub mod acronym;
+pub mod boundary;
+#[cfg(feature = "serde")]
+pub mod cased;
+mod converter;
+mod detect;
+pub mod env;
+#[cfg(feature = "fs")]
+pub mod fs;
+pub mod headers;
+pub mod inflect;
+#[cfg(feature = "serde")]
+pub mod keys;
+pub mod lang;
+pub mod locale;
+pub mod refactor;
+#[cfg(feature = "slug")]
+pub mod slug;
+mod title;
+#[cfg(feature = "transliterate")]
+pub mod transliterate;
+#[cfg(feature = "unicode-segmentation")]
+mod writer;
+
+pub use converter::ExtraConverter;
+pub use detect::{detect_case, Confidence};
+#[cfg(feature = "unicode-segmentation")]
+pub use writer::{convert_into, CaseWriter};
+
+use acronym::Acronyms;
+use locale::Locale;
+
+#[cfg(feature = "random")]
+use core::cell::RefCell;
 #[cfg(feature = "random")]
 use rand::prelude::*;
 
+/// The error returned when parsing a string that doesn't name any variant of a type
+/// deriving `CaseStr` from the `convert_case_extras_derive` crate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseCaseStrError {
+    input: String,
+}
+
+impl ParseCaseStrError {
+    /// Creates an error for the given unrecognized input.
+    pub fn new(input: &str) -> Self {
+        ParseCaseStrError {
+            input: input.to_string(),
+        }
+    }
+
+    /// The string that could not be parsed.
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+}
+
+impl core::fmt::Display for ParseCaseStrError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "unknown variant `{}`", self.input)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseCaseStrError {}
+
+/// A pattern that can carry configuration and state.
+///
+/// [`Pattern::Custom`] only accepts a non-capturing function pointer, so a pattern
+/// built from it cannot hold a seed, a dictionary, or a locale.  Any type implementing
+/// `ExtraPattern` can, and can be applied with an [`ExtraConverter`].  The trait is
+/// object safe, and is implemented for [`Pattern`] so that the patterns from
+/// `convert_case` can be used anywhere an `ExtraPattern` is expected.
+/// ```
+/// use convert_case::{Case, Pattern};
+/// use convert_case_extras::{ExtraConverter, ExtraPattern};
+///
+/// struct Suffix(&'static str);
+///
+/// impl ExtraPattern for Suffix {
+///     fn mutate(&self, words: &[&str]) -> Vec<String> {
+///         words.iter().map(|word| format!("{}{}", word, self.0)).collect()
+///     }
+/// }
+///
+/// let conv = ExtraConverter::new()
+///     .from_case(Case::Snake)
+///     .set_pattern(Suffix("!"))
+///     .set_delim(" ");
+/// assert_eq!(conv.convert("hello_world"), "hello! world!");
+///
+/// let patterns: Vec<Box<dyn ExtraPattern>> = vec![
+///     Box::new(Pattern::Uppercase),
+///     Box::new(Suffix("?")),
+/// ];
+/// assert_eq!(patterns[1].mutate(&["why"]), vec!["why?"]);
+/// ```
+pub trait ExtraPattern {
+    /// Mutates a list of words, typically by changing the case of each letter.
+    fn mutate(&self, words: &[&str]) -> Vec<String>;
+}
+
+impl ExtraPattern for Pattern {
+    fn mutate(&self, words: &[&str]) -> Vec<String> {
+        Pattern::mutate(self, words)
+    }
+}
+
+impl<P: ExtraPattern + ?Sized> ExtraPattern for &P {
+    fn mutate(&self, words: &[&str]) -> Vec<String> {
+        (**self).mutate(words)
+    }
+}
+
+impl<P: ExtraPattern + ?Sized> ExtraPattern for Box<P> {
+    fn mutate(&self, words: &[&str]) -> Vec<String> {
+        (**self).mutate(words)
+    }
+}
+
 pub mod pattern {
     use super::*;
 
@@ -28,20 +169,7 @@ pub mod pattern {
     ///     vec!["cASE", "cONVERSION", "lIBRARY"],
     /// );
     /// ```
-    pub const TOGGLE: Pattern = Pattern::Custom(|words| {
-        words
-            .iter()
-            .map(|word| {
-                let mut chars = word.chars();
-
-                if let Some(c) = chars.next() {
-                    [c.to_lowercase().collect(), chars.as_str().to_uppercase()].concat()
-                } else {
-                    String::new()
-                }
-            })
-            .collect()
-    });
+    pub const TOGGLE: Pattern = Pattern::Custom(root_toggle);
 
     /// Makes each letter of each word alternate between lowercase and uppercase.
     ///
@@ -60,34 +188,758 @@ pub mod pattern {
     ///     vec!["aNoThEr", "ExAmPlE"],
     /// );
     /// ```
-    pub const ALTERNATING: Pattern = Pattern::Custom(|words| {
-        let mut upper = false;
-        words
-            .iter()
-            .map(|word| {
-                word.chars()
-                    .map(|letter| {
-                        if letter.is_uppercase() || letter.is_lowercase() {
-                            if upper {
-                                upper = false;
-                                letter.to_uppercase().to_string()
+    pub const ALTERNATING: Pattern = Pattern::Custom(root_alternating);
+
+    /// Makes the first grapheme cluster of each word lowercase and the remaining
+    /// clusters of each word uppercase.
+    ///
+    /// This is [`TOGGLE`] over user-perceived characters instead of `char`s, so a letter
+    /// is cased together with the combining marks that follow it.  Only available with
+    /// the "unicode-segmentation" feature.
+    /// ```
+    /// use convert_case_extras::pattern;
+    ///
+    /// assert_eq!(
+    ///     pattern::GRAPHEME_TOGGLE.mutate(&["E\u{301}cole", "\u{1f469}\u{200d}\u{1f4bb}dev"]),
+    ///     vec!["e\u{301}COLE", "\u{1f469}\u{200d}\u{1f4bb}DEV"],
+    /// );
+    /// ```
+    #[cfg(feature = "unicode-segmentation")]
+    pub const GRAPHEME_TOGGLE: Pattern = Pattern::Custom(root_grapheme_toggle);
+
+    /// Makes each grapheme cluster of each word alternate between lowercase and
+    /// uppercase.
+    ///
+    /// This is [`ALTERNATING`] over user-perceived characters instead of `char`s.  A
+    /// cluster is cased as a whole, and only clusters containing a letter with case
+    /// advance the alternation, so precomposed (NFC) and decomposed (NFD) text alternate
+    /// the same way.  Only available with the "unicode-segmentation" feature.
+    /// ```
+    /// use convert_case_extras::pattern;
+    ///
+    /// assert_eq!(
+    ///     pattern::GRAPHEME_ALTERNATING.mutate(&["\u{3b1}\u{345}βγ", "Case"]),
+    ///     vec!["\u{3b1}\u{345}Βγ", "CaSe"],
+    /// );
+    /// ```
+    #[cfg(feature = "unicode-segmentation")]
+    pub const GRAPHEME_ALTERNATING: Pattern = Pattern::Custom(root_grapheme_alternating);
+
+    /// Capitalizes words in a title following the Associated Press style guide.
+    ///
+    /// Articles, and conjunctions and prepositions of three letters or fewer, are
+    /// lowercase.  The first and last words, and the first word after a colon, are
+    /// always capitalized.  Each part of a hyphenated word is treated as its own word.
+    /// ```
+    /// use convert_case_extras::pattern;
+    ///
+    /// assert_eq!(
+    ///     pattern::TITLE_AP.mutate(&["somewhere", "over", "the", "rainbow"]),
+    ///     vec!["Somewhere", "Over", "the", "Rainbow"],
+    /// );
+    /// ```
+    pub const TITLE_AP: Pattern = Pattern::Custom(title_ap);
+
+    /// Capitalizes words in a title following the Chicago Manual of Style.
+    ///
+    /// Articles, all prepositions regardless of length, and the conjunctions "and",
+    /// "but", "for", "or" and "nor" are lowercase.  The first and last words, and the
+    /// first word after a colon, are always capitalized.
+    /// ```
+    /// use convert_case_extras::pattern;
+    ///
+    /// assert_eq!(
+    ///     pattern::TITLE_CHICAGO.mutate(&["somewhere", "over", "the", "rainbow"]),
+    ///     vec!["Somewhere", "over", "the", "Rainbow"],
+    /// );
+    /// ```
+    pub const TITLE_CHICAGO: Pattern = Pattern::Custom(title_chicago);
+
+    /// Capitalizes words in a title following the American Psychological Association
+    /// style guide.
+    ///
+    /// Articles, and conjunctions and prepositions of three letters or fewer, are
+    /// lowercase, even as the last word.  The first word, and the first word after
+    /// a colon, are always capitalized.
+    /// ```
+    /// use convert_case_extras::pattern;
+    ///
+    /// assert_eq!(
+    ///     pattern::TITLE_APA.mutate(&["learning", "with", "and", "without", "feedback"]),
+    ///     vec!["Learning", "With", "and", "Without", "Feedback"],
+    /// );
+    /// ```
+    pub const TITLE_APA: Pattern = Pattern::Custom(title_apa);
+
+    /// Capitalizes words in a title following the Modern Language Association
+    /// style guide.
+    ///
+    /// Articles, all prepositions, coordinating conjunctions and "to" are lowercase.
+    /// The first and last words, and the first word after a colon, are always capitalized.
+    /// ```
+    /// use convert_case_extras::pattern;
+    ///
+    /// assert_eq!(
+    ///     pattern::TITLE_MLA.mutate(&["a", "room", "with", "a", "view"]),
+    ///     vec!["A", "Room", "with", "a", "View"],
+    /// );
+    /// ```
+    pub const TITLE_MLA: Pattern = Pattern::Custom(title_mla);
+
+    /// Capitalizes the first word and lowercases the rest, like [`Pattern::Sentence`],
+    /// but preserves proper nouns and acronyms.
+    ///
+    /// Words in the default [`Acronyms`] dictionary are written in their canonical
+    /// casing, and words of two or more letters that are already all uppercase are kept.
+    /// This is [`AcronymPattern::sentence`] with
+    /// [`keep_uppercase`](AcronymPattern::keep_uppercase), which can be used instead to
+    /// protect other terms.
+    /// ```
+    /// use convert_case_extras::pattern;
+    ///
+    /// assert_eq!(
+    ///     pattern::SENTENCE_PRESERVING.mutate(&["Sync", "To", "github", "From", "NASA", "ci"]),
+    ///     vec!["Sync", "to", "GitHub", "from", "NASA", "ci"],
+    /// );
+    /// ```
+    pub const SENTENCE_PRESERVING: Pattern = Pattern::Custom(sentence_preserving);
+
+    /// A pattern that writes words from an [`Acronyms`] dictionary in their canonical casing.
+    ///
+    /// Registered initialisms, and initialisms followed by a plural `s`, are written fully
+    /// uppercase, and brand names like `iOS` and `GitHub` are written verbatim.  All other
+    /// words follow the underlying pattern.  In camel case, a first word that is an
+    /// initialism is lowercased as a whole, as is a brand that begins with an uppercase letter.
+    /// ```
+    /// use convert_case::Case;
+    /// use convert_case_extras::{acronym::Acronyms, pattern::AcronymPattern, ExtraConverter};
+    ///
+    /// let conv = ExtraConverter::new()
+    ///     .to_case(Case::Pascal)
+    ///     .set_pattern(AcronymPattern::capital(Acronyms::new()));
+    /// assert_eq!(conv.convert("http_server_url"), "HTTPServerURL");
+    /// assert_eq!(conv.convert("ios_user_ids"), "iOSUserIDs");
+    ///
+    /// let conv = ExtraConverter::new()
+    ///     .to_case(Case::Camel)
+    ///     .set_pattern(AcronymPattern::camel(Acronyms::new()));
+    /// assert_eq!(conv.convert("http_server_url"), "httpServerURL");
+    /// assert_eq!(conv.convert("github_api_token"), "githubAPIToken");
+    /// ```
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct AcronymPattern {
+        kind: AcronymKind,
+        acronyms: Acronyms,
+        keep_uppercase: bool,
+        locale: Locale,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum AcronymKind {
+        Capital,
+        Camel,
+        Sentence,
+    }
+
+    impl AcronymPattern {
+        /// Capitalizes each word, like [`Pattern::Capital`].  Used by pascal, train and
+        /// title case.
+        pub fn capital(acronyms: Acronyms) -> Self {
+            AcronymPattern {
+                kind: AcronymKind::Capital,
+                acronyms,
+                keep_uppercase: false,
+                locale: Locale::Root,
+            }
+        }
+
+        /// Lowercases the first word and capitalizes the rest, like [`Pattern::Camel`].
+        pub fn camel(acronyms: Acronyms) -> Self {
+            AcronymPattern {
+                kind: AcronymKind::Camel,
+                acronyms,
+                keep_uppercase: false,
+                locale: Locale::Root,
+            }
+        }
+
+        /// Capitalizes the first word and lowercases the rest, like [`Pattern::Sentence`].
+        /// ```
+        /// use convert_case_extras::{acronym::Acronyms, pattern::AcronymPattern, ExtraPattern};
+        ///
+        /// let pattern = AcronymPattern::sentence(Acronyms::new());
+        /// assert_eq!(
+        ///     pattern.mutate(&["open", "the", "json", "file", "on", "macos"]),
+        ///     vec!["Open", "the", "JSON", "file", "on", "macOS"],
+        /// );
+        /// ```
+        pub fn sentence(acronyms: Acronyms) -> Self {
+            AcronymPattern {
+                kind: AcronymKind::Sentence,
+                acronyms,
+                keep_uppercase: false,
+                locale: Locale::Root,
+            }
+        }
+
+        /// Keeps words that are already all uppercase, like `NASA` or `CLI`, even when
+        /// they are not in the dictionary.  Only words of two or more letters are kept, so
+        /// that a capitalized `A` in a title is still lowercased.  The first word of camel
+        /// case is lowercased regardless.
+        /// ```
+        /// use convert_case_extras::{acronym::Acronyms, pattern::AcronymPattern, ExtraPattern};
+        ///
+        /// let pattern = AcronymPattern::sentence(Acronyms::new()).keep_uppercase(true);
+        /// assert_eq!(
+        ///     pattern.mutate(&["Export", "As", "PDF", "For", "NASA"]),
+        ///     vec!["Export", "as", "PDF", "for", "NASA"],
+        /// );
+        /// ```
+        pub fn keep_uppercase(mut self, keep_uppercase: bool) -> Self {
+            self.keep_uppercase = keep_uppercase;
+            self
+        }
+
+        /// Cases the words that aren't in the dictionary following the rules of `locale`.
+        /// ```
+        /// use convert_case::Case;
+        /// use convert_case_extras::{
+        ///     acronym::Acronyms, locale::Locale, pattern::AcronymPattern, ExtraConverter,
+        /// };
+        ///
+        /// let conv = ExtraConverter::new().to_case(Case::Pascal).set_pattern(
+        ///     AcronymPattern::capital(Acronyms::new()).locale(Locale::Turkish),
+        /// );
+        /// assert_eq!(conv.convert("istanbul_api_istemcisi"), "İstanbulAPIİstemcisi");
+        /// ```
+        pub fn locale(mut self, locale: Locale) -> Self {
+            self.locale = locale;
+            self
+        }
+
+        /// The dictionary consulted by this pattern.
+        pub fn acronyms(&self) -> &Acronyms {
+            &self.acronyms
+        }
+    }
+
+    /// True for a word with at least two letters, all of them uppercase.
+    fn is_uppercase_word(word: &str) -> bool {
+        let mut letters = word
+            .chars()
+            .filter(|c| c.is_lowercase() || c.is_uppercase());
+        letters.clone().nth(1).is_some() && letters.all(char::is_uppercase)
+    }
+
+    impl ExtraPattern for AcronymPattern {
+        fn mutate(&self, words: &[&str]) -> Vec<String> {
+            words
+                .iter()
+                .enumerate()
+                .map(|(i, word)| {
+                    let camel_start = self.kind == AcronymKind::Camel && i == 0;
+                    if self.keep_uppercase && !camel_start && is_uppercase_word(word) {
+                        return word.to_string();
+                    }
+                    match (self.kind, self.acronyms.canonical(word)) {
+                        (AcronymKind::Camel, Some(canonical))
+                            if i == 0 && !canonical.starts_with(char::is_lowercase) =>
+                        {
+                            self.locale.to_lowercase(word)
+                        }
+                        (_, Some(canonical)) => canonical,
+                        (AcronymKind::Camel, None) if i == 0 => self.locale.to_lowercase(word),
+                        (AcronymKind::Sentence, None) if i > 0 => self.locale.to_lowercase(word),
+                        (_, None) => self.locale.to_capital(word),
+                    }
+                })
+                .collect()
+        }
+    }
+
+    /// An alternating pattern with a configurable phase, period, and what resets and
+    /// advances it.
+    ///
+    /// `Alternating::new()` behaves like [`ALTERNATING`]: it starts lowercase, counts only
+    /// letters, and carries the alternation across words.  Every `period`th counted
+    /// character is uppercase and the rest are lowercase, so a period of 3 uppercases
+    /// every third letter.  Characters without case are never changed, even when they
+    /// are counted.
+    /// ```
+    /// use convert_case::Case;
+    /// use convert_case_extras::{pattern::Alternating, ExtraConverter};
+    ///
+    /// let conv = ExtraConverter::new()
+    ///     .to_case(Case::Lower)
+    ///     .set_pattern(Alternating::new().start_upper(true).reset_per_word(true));
+    /// assert_eq!(conv.convert("one two three"), "OnE TwO ThReE");
+    ///
+    /// let conv = conv.set_pattern(Alternating::new().period(3));
+    /// assert_eq!(conv.convert("one two three"), "onE twO thRee");
+    /// ```
+    ///
+    /// The configuration can also be made into a [`Pattern`] with [`alternating`], or a
+    /// [`Case`] with [`case::alternating`].
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct Alternating {
+        start_upper: bool,
+        reset_per_word: bool,
+        count_non_letters: bool,
+        period: u32,
+        locale: Locale,
+    }
+
+    /// Every locale, in the order of their codes in [`Alternating::to_bits`].
+    const LOCALES: [Locale; 5] = [
+        Locale::Root,
+        Locale::Turkish,
+        Locale::Azeri,
+        Locale::Lithuanian,
+        Locale::Greek,
+    ];
+
+    impl Default for Alternating {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    impl Alternating {
+        /// Alternates like [`ALTERNATING`].
+        pub const fn new() -> Self {
+            Alternating {
+                start_upper: false,
+                reset_per_word: false,
+                count_non_letters: false,
+                period: 2,
+                locale: Locale::Root,
+            }
+        }
+
+        /// Whether the first counted character is uppercase.  With a period longer
+        /// than 2, the uppercase character is first in each period instead of last.
+        pub const fn start_upper(mut self, start_upper: bool) -> Self {
+            self.start_upper = start_upper;
+            self
+        }
+
+        /// Whether each word starts the alternation over, instead of continuing from
+        /// the last word.
+        pub const fn reset_per_word(mut self, reset_per_word: bool) -> Self {
+            self.reset_per_word = reset_per_word;
+            self
+        }
+
+        /// Whether characters without case, like digits and punctuation, take a turn
+        /// in the alternation.
+        pub const fn count_non_letters(mut self, count_non_letters: bool) -> Self {
+            self.count_non_letters = count_non_letters;
+            self
+        }
+
+        /// How many counted characters there are for each uppercase one.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `period` is less than 2, or doesn't fit in 26 bits.
+        pub const fn period(mut self, period: u32) -> Self {
+            assert!(
+                period >= 2,
+                "the period of an alternating pattern must be at least 2"
+            );
+            assert!(
+                period < 1 << 26,
+                "the period of an alternating pattern is too long"
+            );
+            self.period = period;
+            self
+        }
+
+        /// Lowercases and uppercases letters following the rules of `locale`.
+        /// ```
+        /// use convert_case::Case;
+        /// use convert_case_extras::{locale::Locale, pattern::Alternating, ExtraConverter};
+        ///
+        /// let conv = ExtraConverter::new()
+        ///     .to_case(Case::Lower)
+        ///     .set_pattern(Alternating::new().locale(Locale::Turkish));
+        /// assert_eq!(conv.convert("IIII"), "ıIıI");
+        /// ```
+        pub const fn locale(mut self, locale: Locale) -> Self {
+            self.locale = locale;
+            self
+        }
+
+        /// Encodes this configuration as a number, for [`alternating`] and
+        /// [`case::alternating`].
+        pub const fn to_bits(self) -> u32 {
+            self.start_upper as u32
+                | (self.reset_per_word as u32) << 1
+                | (self.count_non_letters as u32) << 2
+                | (self.locale as u32) << 3
+                | self.period << 6
+        }
+
+        /// Decodes a configuration from [`to_bits`](Self::to_bits).
+        ///
+        /// # Panics
+        ///
+        /// Panics if `bits` is not the encoding of any configuration, because its period
+        /// is less than 2 or its locale doesn't exist.
+        pub const fn from_bits(bits: u32) -> Self {
+            let locale = (bits >> 3 & 0b111) as usize;
+            let period = bits >> 6;
+            assert!(
+                locale < LOCALES.len(),
+                "the bits of an alternating pattern have an unknown locale"
+            );
+            assert!(
+                period >= 2,
+                "the bits of an alternating pattern have a period less than 2"
+            );
+            Alternating {
+                start_upper: bits & 1 != 0,
+                reset_per_word: bits & 1 << 1 != 0,
+                count_non_letters: bits & 1 << 2 != 0,
+                period,
+                locale: LOCALES[locale],
+            }
+        }
+    }
+
+    impl ExtraPattern for Alternating {
+        fn mutate(&self, words: &[&str]) -> Vec<String> {
+            let locale = self.locale;
+            let period = self.period as usize;
+            let mut count = 0;
+            words
+                .iter()
+                .map(|word| {
+                    if self.reset_per_word {
+                        count = 0;
+                    }
+                    locale.case_chars(word, |c| {
+                        let letter = c.is_uppercase() || c.is_lowercase();
+                        if !letter && !self.count_non_letters {
+                            return false;
+                        }
+                        let phase = count % period;
+                        count += 1;
+                        letter
+                            && if self.start_upper {
+                                phase == 0
                             } else {
-                                upper = true;
-                                letter.to_lowercase().to_string()
+                                phase == period - 1
                             }
+                    })
+                })
+                .collect()
+        }
+    }
+
+    /// Makes a [`Pattern`] from the [`Alternating`] configuration encoded in `BITS`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `BITS` weren't made by [`Alternating::to_bits`], which is a compile
+    /// error when the pattern is a constant.
+    /// ```
+    /// use convert_case::{Case, Casing, Pattern};
+    /// use convert_case_extras::pattern::{self, Alternating};
+    ///
+    /// const SPONGE: Pattern = pattern::alternating::<{ Alternating::new().period(3).to_bits() }>();
+    /// assert_eq!(SPONGE.mutate(&["abcdef"]), vec!["abCdeF"]);
+    /// ```
+    pub const fn alternating<const BITS: u32>() -> Pattern {
+        Alternating::from_bits(BITS);
+        Pattern::Custom(|words| Alternating::from_bits(BITS).mutate(words))
+    }
+
+    /// A standard pattern that follows the casing rules of a [`Locale`].
+    ///
+    /// Each constructor mirrors a pattern from `convert_case` or this crate, but
+    /// lowercases and uppercases letters with [`Locale`] instead of the default
+    /// Unicode mappings.  Use it with an [`ExtraConverter`].
+    /// ```
+    /// use convert_case::Case;
+    /// use convert_case_extras::{locale::Locale, pattern::Localized, ExtraConverter};
+    ///
+    /// let conv = ExtraConverter::new()
+    ///     .to_case(Case::Snake)
+    ///     .set_pattern(Localized::lowercase(Locale::Turkish));
+    /// assert_eq!(conv.convert("KIRMIZI IŞIK"), "kırmızı_ışık");
+    ///
+    /// let conv = ExtraConverter::new()
+    ///     .to_case(Case::Pascal)
+    ///     .set_pattern(Localized::capital(Locale::Turkish));
+    /// assert_eq!(conv.convert("izmir ili"), "İzmirİli");
+    /// ```
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct Localized {
+        kind: LocalizedKind,
+        locale: Locale,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    enum LocalizedKind {
+        Lowercase,
+        Uppercase,
+        Capital,
+        Camel,
+        Sentence,
+        Toggle,
+        Alternating,
+        TitleAp,
+        TitleChicago,
+        TitleApa,
+        TitleMla,
+    }
+
+    impl Localized {
+        /// Lowercase every letter, like [`Pattern::Lowercase`].
+        pub const fn lowercase(locale: Locale) -> Self {
+            Localized {
+                kind: LocalizedKind::Lowercase,
+                locale,
+            }
+        }
+
+        /// Uppercase every letter, like [`Pattern::Uppercase`].
+        pub const fn uppercase(locale: Locale) -> Self {
+            Localized {
+                kind: LocalizedKind::Uppercase,
+                locale,
+            }
+        }
+
+        /// Uppercase the first letter of each word, like [`Pattern::Capital`].
+        pub const fn capital(locale: Locale) -> Self {
+            Localized {
+                kind: LocalizedKind::Capital,
+                locale,
+            }
+        }
+
+        /// Capital words except the first, which is lowercase, like [`Pattern::Camel`].
+        pub const fn camel(locale: Locale) -> Self {
+            Localized {
+                kind: LocalizedKind::Camel,
+                locale,
+            }
+        }
+
+        /// Lowercase words except the first, which is capital, like [`Pattern::Sentence`].
+        pub const fn sentence(locale: Locale) -> Self {
+            Localized {
+                kind: LocalizedKind::Sentence,
+                locale,
+            }
+        }
+
+        /// Like [`TOGGLE`].
+        pub const fn toggle(locale: Locale) -> Self {
+            Localized {
+                kind: LocalizedKind::Toggle,
+                locale,
+            }
+        }
+
+        /// Like [`ALTERNATING`].
+        pub const fn alternating(locale: Locale) -> Self {
+            Localized {
+                kind: LocalizedKind::Alternating,
+                locale,
+            }
+        }
+
+        /// Like [`TITLE_AP`].
+        /// ```
+        /// use convert_case_extras::{locale::Locale, pattern::Localized, ExtraPattern};
+        ///
+        /// assert_eq!(
+        ///     Localized::title_ap(Locale::Turkish).mutate(&["istanbul", "to", "izmir"]),
+        ///     vec!["İstanbul", "to", "İzmir"],
+        /// );
+        /// ```
+        pub const fn title_ap(locale: Locale) -> Self {
+            Localized {
+                kind: LocalizedKind::TitleAp,
+                locale,
+            }
+        }
+
+        /// Like [`TITLE_CHICAGO`].
+        pub const fn title_chicago(locale: Locale) -> Self {
+            Localized {
+                kind: LocalizedKind::TitleChicago,
+                locale,
+            }
+        }
+
+        /// Like [`TITLE_APA`].
+        pub const fn title_apa(locale: Locale) -> Self {
+            Localized {
+                kind: LocalizedKind::TitleApa,
+                locale,
+            }
+        }
+
+        /// Like [`TITLE_MLA`].
+        pub const fn title_mla(locale: Locale) -> Self {
+            Localized {
+                kind: LocalizedKind::TitleMla,
+                locale,
+            }
+        }
+
+        /// The locale whose casing rules are followed.
+        pub const fn locale(&self) -> Locale {
+            self.locale
+        }
+    }
+
+    impl ExtraPattern for Localized {
+        fn mutate(&self, words: &[&str]) -> Vec<String> {
+            let locale = self.locale;
+            match self.kind {
+                LocalizedKind::Lowercase => words.iter().map(|w| locale.to_lowercase(w)).collect(),
+                LocalizedKind::Uppercase => words.iter().map(|w| locale.to_uppercase(w)).collect(),
+                LocalizedKind::Capital => words.iter().map(|w| locale.to_capital(w)).collect(),
+                LocalizedKind::Camel => words
+                    .iter()
+                    .enumerate()
+                    .map(|(i, w)| {
+                        if i == 0 {
+                            locale.to_lowercase(w)
+                        } else {
+                            locale.to_capital(w)
+                        }
+                    })
+                    .collect(),
+                LocalizedKind::Sentence => words
+                    .iter()
+                    .enumerate()
+                    .map(|(i, w)| {
+                        if i == 0 {
+                            locale.to_capital(w)
                         } else {
-                            letter.to_string()
+                            locale.to_lowercase(w)
                         }
                     })
-                    .collect()
+                    .collect(),
+                LocalizedKind::Toggle => toggle_words(words, locale),
+                LocalizedKind::Alternating => alternating_words(words, locale),
+                LocalizedKind::TitleAp => title::AP.mutate(words, locale),
+                LocalizedKind::TitleChicago => title::CHICAGO.mutate(words, locale),
+                LocalizedKind::TitleApa => title::APA.mutate(words, locale),
+                LocalizedKind::TitleMla => title::MLA.mutate(words, locale),
+            }
+        }
+    }
+
+    // The patterns of the deterministic cases are named functions that are never inlined,
+    // so that each has a single address.  `Case` compares patterns by function pointer,
+    // and a closure in a constant can be copied into every crate that uses it.
+
+    #[inline(never)]
+    fn root_toggle(words: &[&str]) -> Vec<String> {
+        toggle_words(words, Locale::Root)
+    }
+
+    #[inline(never)]
+    fn root_alternating(words: &[&str]) -> Vec<String> {
+        alternating_words(words, Locale::Root)
+    }
+
+    #[cfg(feature = "unicode-segmentation")]
+    #[inline(never)]
+    fn root_grapheme_toggle(words: &[&str]) -> Vec<String> {
+        grapheme_toggle_words(words, Locale::Root)
+    }
+
+    #[cfg(feature = "unicode-segmentation")]
+    #[inline(never)]
+    fn root_grapheme_alternating(words: &[&str]) -> Vec<String> {
+        grapheme_alternating_words(words, Locale::Root)
+    }
+
+    #[inline(never)]
+    fn title_ap(words: &[&str]) -> Vec<String> {
+        title::AP.mutate(words, Locale::Root)
+    }
+
+    #[inline(never)]
+    fn title_chicago(words: &[&str]) -> Vec<String> {
+        title::CHICAGO.mutate(words, Locale::Root)
+    }
+
+    #[inline(never)]
+    fn title_apa(words: &[&str]) -> Vec<String> {
+        title::APA.mutate(words, Locale::Root)
+    }
+
+    #[inline(never)]
+    fn title_mla(words: &[&str]) -> Vec<String> {
+        title::MLA.mutate(words, Locale::Root)
+    }
+
+    #[inline(never)]
+    fn sentence_preserving(words: &[&str]) -> Vec<String> {
+        AcronymPattern::sentence(Acronyms::new())
+            .keep_uppercase(true)
+            .mutate(words)
+    }
+
+    fn toggle_words(words: &[&str], locale: Locale) -> Vec<String> {
+        words.iter().map(|word| locale.to_toggle(word)).collect()
+    }
+
+    fn alternating_words(words: &[&str], locale: Locale) -> Vec<String> {
+        Alternating::new().locale(locale).mutate(words)
+    }
+
+    #[cfg(feature = "unicode-segmentation")]
+    fn grapheme_toggle_words(words: &[&str], locale: Locale) -> Vec<String> {
+        use unicode_segmentation::UnicodeSegmentation;
+
+        words
+            .iter()
+            .map(|word| {
+                let first = word.graphemes(true).next().map_or(0, |g| g.chars().count());
+                let mut i = 0;
+                locale.case_chars(word, |_| {
+                    i += 1;
+                    i > first
+                })
             })
             .collect()
-    });
+    }
+
+    #[cfg(feature = "unicode-segmentation")]
+    fn grapheme_alternating_words(words: &[&str], locale: Locale) -> Vec<String> {
+        use unicode_segmentation::UnicodeSegmentation;
+
+        let mut upper = false;
+        words
+            .iter()
+            .map(|word| {
+                // Whether to uppercase each char, decided once per cluster
+                let mut chars_upper = word.graphemes(true).flat_map(|g| {
+                    let cased = g.chars().any(|c| c.is_uppercase() || c.is_lowercase());
+                    let to_upper = cased && upper;
+                    upper ^= cased;
+                    core::iter::repeat_n(to_upper, g.chars().count())
+                });
+                locale.case_chars(word, |_| chars_upper.next().unwrap_or(false))
+            })
+            .collect()
+    }
 
     // #[doc(cfg(feature = "random"))]
     /// Lowercases or uppercases each letter uniformly randomly.
     ///
-    /// This uses the `rand` crate and is only available with the "random" feature.
+    /// This uses the `rand` crate and is only available with the "random" and "std"
+    /// features.  For reproducible output, use a seeded [`RandomPattern`] instead.
     /// ```
     /// # #[cfg(any(doc, feature = "random"))]
     /// use convert_case_extras::pattern;
@@ -95,24 +947,9 @@ pub mod pattern {
     /// pattern::RANDOM.mutate(&["Case", "CONVERSION", "library"]);
     /// // "casE", "coNVeRSiOn", "lIBraRY"
     /// ```
-    #[cfg(feature = "random")]
-    pub const RANDOM: Pattern = Pattern::Custom(|words| {
-        let mut rng = rand::thread_rng();
-        words
-            .iter()
-            .map(|word| {
-                word.chars()
-                    .map(|letter| {
-                        if rng.gen::<f32>() > 0.5 {
-                            letter.to_uppercase().to_string()
-                        } else {
-                            letter.to_lowercase().to_string()
-                        }
-                    })
-                    .collect()
-            })
-            .collect()
-    });
+    #[cfg(all(feature = "random", feature = "std"))]
+    pub const RANDOM: Pattern =
+        Pattern::Custom(|words| random_words(&mut rand::thread_rng(), words, Locale::Root));
 
     /// Case each letter in random-like patterns.
     ///
@@ -124,7 +961,8 @@ pub mod pattern {
     /// or all uppercase.  This uses the `rand` crate and is only available with the "random"
     /// feature.
     ///
-    /// This uses the `rand` crate and is only available with the "random" feature.
+    /// This uses the `rand` crate and is only available with the "random" and "std"
+    /// features.  For reproducible output, use a seeded [`RandomPattern`] instead.
     /// ```
     /// # #[cfg(any(doc, feature = "random"))]
     /// use convert_case_extras::pattern;
@@ -132,43 +970,172 @@ pub mod pattern {
     /// pattern::PSEUDO_RANDOM.mutate(&["Case", "CONVERSION", "library"]);
     /// // "cAsE", "cONveRSioN", "lIBrAry"
     /// ```
+    #[cfg(all(feature = "random", feature = "std"))]
+    pub const PSEUDO_RANDOM: Pattern =
+        Pattern::Custom(|words| pseudo_random_words(&mut rand::thread_rng(), words, Locale::Root));
+
+    /// A random or pseudo-random pattern driven by a random number generator you control.
+    ///
+    /// [`RANDOM`] and [`PSEUDO_RANDOM`] draw from `rand::thread_rng()`, so their output
+    /// can never be reproduced.  A `RandomPattern` owns its generator instead, so the
+    /// same seed always yields the same sequence of casings.  Seeded patterns use
+    /// [`ChaCha8Rng`](rand_chacha::ChaCha8Rng), whose output is stable across platforms
+    /// and releases.
+    ///
+    /// The generator advances with each call to [`mutate`](RandomPattern::mutate), just like
+    /// any other random number generator.
+    ///
+    /// This uses the `rand` crate and is only available with the "random" feature.
+    /// ```
+    /// use convert_case_extras::pattern::RandomPattern;
+    ///
+    /// let words = ["Case", "CONVERSION", "library"];
+    /// assert_eq!(
+    ///     RandomPattern::with_seed(7).mutate(&words),
+    ///     RandomPattern::with_seed(7).mutate(&words),
+    /// );
+    /// ```
+    #[cfg(feature = "random")]
+    #[derive(Debug, Clone)]
+    pub struct RandomPattern<R = rand_chacha::ChaCha8Rng> {
+        rng: RefCell<R>,
+        pseudo: bool,
+        locale: Locale,
+    }
+
+    #[cfg(feature = "random")]
+    impl RandomPattern {
+        /// Creates a pattern that randomizes each letter using a generator seeded with `seed`.
+        pub fn with_seed(seed: u64) -> Self {
+            Self::with_rng(rand_chacha::ChaCha8Rng::seed_from_u64(seed))
+        }
+    }
+
     #[cfg(feature = "random")]
-    pub const PSEUDO_RANDOM: Pattern = Pattern::Custom(|words| {
-        let mut rng = rand::thread_rng();
+    impl<R: RngCore> RandomPattern<R> {
+        /// Creates a pattern that randomizes each letter using the given generator.
+        ///
+        /// Any [`RngCore`] can be used, including generators that don't need the "std"
+        /// feature, such as a hardware generator on an embedded target.
+        /// ```
+        /// use convert_case_extras::pattern::RandomPattern;
+        /// use rand::SeedableRng;
+        /// use rand_chacha::ChaCha20Rng;
+        ///
+        /// let pattern = RandomPattern::with_rng(ChaCha20Rng::seed_from_u64(7));
+        /// let words = pattern.mutate(&["Case", "CONVERSION"]);
+        /// assert_eq!(words[0].to_lowercase(), "case");
+        /// ```
+        /// ```
+        /// use convert_case_extras::pattern::RandomPattern;
+        /// use rand::RngCore;
+        ///
+        /// // Every letter is uppercase when the generator only returns its maximum
+        /// struct Max;
+        ///
+        /// impl RngCore for Max {
+        ///     fn next_u32(&mut self) -> u32 { u32::MAX }
+        ///     fn next_u64(&mut self) -> u64 { u64::MAX }
+        ///     fn fill_bytes(&mut self, dest: &mut [u8]) { dest.fill(u8::MAX) }
+        ///     fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        ///         Ok(self.fill_bytes(dest))
+        ///     }
+        /// }
+        ///
+        /// let pattern = RandomPattern::with_rng(Max);
+        /// assert_eq!(pattern.mutate(&["Case", "conversion"]), ["CASE", "CONVERSION"]);
+        /// ```
+        pub fn with_rng(rng: R) -> Self {
+            RandomPattern {
+                rng: RefCell::new(rng),
+                pseudo: false,
+                locale: Locale::Root,
+            }
+        }
+
+        /// Uses the pseudo-random algorithm of [`PSEUDO_RANDOM`] instead of randomizing
+        /// each letter independently.
+        /// ```
+        /// use convert_case_extras::pattern::RandomPattern;
+        ///
+        /// let pattern = RandomPattern::with_seed(7).pseudo();
+        /// let word = pattern.mutate(&["conversion"]).concat();
+        /// assert!(!word.contains("ccc"));
+        /// ```
+        pub fn pseudo(self) -> Self {
+            RandomPattern {
+                pseudo: true,
+                ..self
+            }
+        }
+
+        /// Follows the casing rules of `locale` when changing the case of a letter.
+        /// ```
+        /// use convert_case_extras::{locale::Locale, pattern::RandomPattern};
+        ///
+        /// let pattern = RandomPattern::with_seed(7).with_locale(Locale::Turkish);
+        /// let words = pattern.mutate(&["iiiiiiii"]);
+        /// assert!(words[0].chars().all(|c| c == 'i' || c == 'İ'));
+        /// ```
+        pub fn with_locale(self, locale: Locale) -> Self {
+            RandomPattern { locale, ..self }
+        }
+
+        /// Mutates each word with the next values from the generator.
+        pub fn mutate(&self, words: &[&str]) -> Vec<String> {
+            let mut rng = self.rng.borrow_mut();
+            if self.pseudo {
+                pseudo_random_words(&mut *rng, words, self.locale)
+            } else {
+                random_words(&mut *rng, words, self.locale)
+            }
+        }
+    }
+
+    #[cfg(feature = "random")]
+    impl<R: RngCore> ExtraPattern for RandomPattern<R> {
+        fn mutate(&self, words: &[&str]) -> Vec<String> {
+            RandomPattern::mutate(self, words)
+        }
+    }
+
+    #[cfg(feature = "random")]
+    fn random_words<R: Rng>(rng: &mut R, words: &[&str], locale: Locale) -> Vec<String> {
+        words
+            .iter()
+            .map(|word| locale.case_chars(word, |_| rng.gen::<f32>() > 0.5))
+            .collect()
+    }
 
+    #[cfg(feature = "random")]
+    fn pseudo_random_words<R: Rng>(rng: &mut R, words: &[&str], locale: Locale) -> Vec<String> {
         // Keeps track of when to alternate
         let mut alt: Option<bool> = None;
         words
             .iter()
             .map(|word| {
-                word.chars()
-                    .map(|letter| {
-                        match alt {
-                            // No existing pattern, start one
-                            None => {
-                                if rng.gen::<f32>() > 0.5 {
-                                    alt = Some(false); // Make the next char lower
-                                    letter.to_uppercase().to_string()
-                                } else {
-                                    alt = Some(true); // Make the next char upper
-                                    letter.to_lowercase().to_string()
-                                }
-                            }
-                            // Existing pattern, do what it says
-                            Some(upper) => {
-                                alt = None;
-                                if upper {
-                                    letter.to_uppercase().to_string()
-                                } else {
-                                    letter.to_lowercase().to_string()
-                                }
+                locale.case_chars(word, |_| {
+                    match alt {
+                        // No existing pattern, start one
+                        None => {
+                            if rng.gen::<f32>() > 0.5 {
+                                alt = Some(false); // Make the next char lower
+                                true
+                            } else {
+                                alt = Some(true); // Make the next char upper
+                                false
                             }
                         }
-                    })
-                    .collect()
+                        // Existing pattern, do what it says
+                        Some(upper) => {
+                            alt = None;
+                            upper
+                        }
+                    }
+                })
             })
             .collect()
-    });
+    }
 }
 
 pub mod case {
@@ -208,11 +1175,307 @@ pub mod case {
         delim: " ",
     };
 
+    /// Toggle case over grapheme clusters.  Strings are delimited by spaces.  The first
+    /// user-perceived character of each word is lowercase and the rest are uppercase,
+    /// and a character is never split from its combining marks.  Only available with the
+    /// "unicode-segmentation" feature.
+    /// * Boundaries: [Space](Boundary::Space)
+    /// * Pattern: [Grapheme toggle](pattern::GRAPHEME_TOGGLE)
+    /// * Delimeter: Space `" "`
+    ///
+    /// ```
+    /// use convert_case::Casing;
+    /// use convert_case_extras::case;
+    /// assert_eq!("My variable NAME".to_case(case::GRAPHEME_TOGGLE), "mY vARIABLE nAME");
+    /// ```
+    #[cfg(feature = "unicode-segmentation")]
+    pub const GRAPHEME_TOGGLE: Case = Case::Custom {
+        boundaries: &[Boundary::Space],
+        pattern: pattern::GRAPHEME_TOGGLE,
+        delim: " ",
+    };
+
+    /// Alternating case over grapheme clusters.  Strings are delimited by spaces, and
+    /// user-perceived characters alternate between lowercase and uppercase.  Only
+    /// available with the "unicode-segmentation" feature.
+    /// * Boundaries: [Space](Boundary::Space)
+    /// * Pattern: [Grapheme alternating](pattern::GRAPHEME_ALTERNATING)
+    /// * Delimeter: Space `" "`
+    ///
+    /// ```
+    /// use convert_case::Casing;
+    /// use convert_case_extras::case;
+    /// assert_eq!(
+    ///     "Cafe\u{301} NAME".to_case(case::GRAPHEME_ALTERNATING),
+    ///     "cAfE\u{301} nAmE",
+    /// );
+    /// ```
+    #[cfg(feature = "unicode-segmentation")]
+    pub const GRAPHEME_ALTERNATING: Case = Case::Custom {
+        boundaries: &[Boundary::Space],
+        pattern: pattern::GRAPHEME_ALTERNATING,
+        delim: " ",
+    };
+
+    /// Alternating case with the [`Alternating`](pattern::Alternating) configuration
+    /// encoded in `BITS`.  Strings are delimited by spaces.
+    /// * Boundaries: [Space](Boundary::Space)
+    /// * Pattern: [Alternating](pattern::alternating)
+    /// * Delimeter: Space `" "`
+    ///
+    /// ```
+    /// use convert_case::{Case, Casing};
+    /// use convert_case_extras::{case, pattern::Alternating};
+    ///
+    /// const SHOUTY: Case = case::alternating::<{
+    ///     Alternating::new().start_upper(true).reset_per_word(true).to_bits()
+    /// }>();
+    /// assert_eq!("My variable NAME".to_case(SHOUTY), "My VaRiAbLe NaMe");
+    /// ```
+    pub const fn alternating<const BITS: u32>() -> Case<'static> {
+        Case::Custom {
+            boundaries: &[Boundary::Space],
+            pattern: pattern::alternating::<BITS>(),
+            delim: " ",
+        }
+    }
+
+    /// Title case following the Associated Press style guide.  Strings are delimited by
+    /// spaces, and minor words are lowercase unless they begin or end the title.
+    /// * Boundaries: [Space](Boundary::Space)
+    /// * Pattern: [AP title](pattern::TITLE_AP)
+    /// * Delimeter: Space `" "`
+    ///
+    /// ```
+    /// use convert_case::Casing;
+    /// use convert_case_extras::case;
+    /// assert_eq!(
+    ///     "star_wars:_the_empire_strikes_back".to_case(case::TITLE_AP),
+    ///     "Star Wars: The Empire Strikes Back",
+    /// );
+    /// ```
+    pub const TITLE_AP: Case = Case::Custom {
+        boundaries: &[Boundary::Space],
+        pattern: pattern::TITLE_AP,
+        delim: " ",
+    };
+
+    /// Title case following the Chicago Manual of Style.  Strings are delimited by
+    /// spaces, and minor words are lowercase unless they begin or end the title.
+    /// * Boundaries: [Space](Boundary::Space)
+    /// * Pattern: [Chicago title](pattern::TITLE_CHICAGO)
+    /// * Delimeter: Space `" "`
+    ///
+    /// ```
+    /// use convert_case::Casing;
+    /// use convert_case_extras::case;
+    /// assert_eq!(
+    ///     "a-walk-through-the-forest".to_case(case::TITLE_CHICAGO),
+    ///     "A Walk through the Forest",
+    /// );
+    /// ```
+    pub const TITLE_CHICAGO: Case = Case::Custom {
+        boundaries: &[Boundary::Space],
+        pattern: pattern::TITLE_CHICAGO,
+        delim: " ",
+    };
+
+    /// Title case following the American Psychological Association style guide.  Strings
+    /// are delimited by spaces, and minor words are lowercase unless they begin the title.
+    /// * Boundaries: [Space](Boundary::Space)
+    /// * Pattern: [APA title](pattern::TITLE_APA)
+    /// * Delimeter: Space `" "`
+    ///
+    /// ```
+    /// use convert_case::Casing;
+    /// use convert_case_extras::case;
+    /// assert_eq!(
+    ///     "theRoleOfSleepInMemory".to_case(case::TITLE_APA),
+    ///     "The Role of Sleep in Memory",
+    /// );
+    /// ```
+    pub const TITLE_APA: Case = Case::Custom {
+        boundaries: &[Boundary::Space],
+        pattern: pattern::TITLE_APA,
+        delim: " ",
+    };
+
+    /// Title case following the Modern Language Association style guide.  Strings are
+    /// delimited by spaces, and minor words are lowercase unless they begin or end the title.
+    /// * Boundaries: [Space](Boundary::Space)
+    /// * Pattern: [MLA title](pattern::TITLE_MLA)
+    /// * Delimeter: Space `" "`
+    ///
+    /// ```
+    /// use convert_case::Casing;
+    /// use convert_case_extras::case;
+    /// assert_eq!("OF MICE AND MEN".to_case(case::TITLE_MLA), "Of Mice and Men");
+    /// ```
+    pub const TITLE_MLA: Case = Case::Custom {
+        boundaries: &[Boundary::Space],
+        pattern: pattern::TITLE_MLA,
+        delim: " ",
+    };
+
+    /// Sentence case that preserves proper nouns and acronyms.  Strings are delimited by
+    /// spaces.  The first word is capitalized and the rest are lowercase, except for the
+    /// terms of the default [`Acronyms`] dictionary, which keep
+    /// their canonical casing, and words that are already all uppercase.
+    /// * Boundaries: [Space](Boundary::Space)
+    /// * Pattern: [Sentence preserving](pattern::SENTENCE_PRESERVING)
+    /// * Delimeter: Space `" "`
+    ///
+    /// Since words like `GitHub` would be split on their lower-upper boundaries, convert
+    /// from this case, or from another case without those boundaries.
+    /// ```
+    /// use convert_case::{Case, Casing};
+    /// use convert_case_extras::case;
+    ///
+    /// assert_eq!(
+    ///     "Publish Docs To GitHub Pages Via CI"
+    ///         .from_case(case::SENTENCE_PRESERVING)
+    ///         .to_case(case::SENTENCE_PRESERVING),
+    ///     "Publish docs to GitHub pages via CI",
+    /// );
+    /// assert_eq!(
+    ///     "update_ios_sdk_urls"
+    ///         .from_case(Case::Snake)
+    ///         .to_case(case::SENTENCE_PRESERVING),
+    ///     "Update iOS sdk URLs",
+    /// );
+    /// ```
+    ///
+    /// To protect other terms, use an
+    /// [`AcronymPattern`](pattern::AcronymPattern) with your own dictionary.
+    /// ```
+    /// use convert_case_extras::{acronym::Acronyms, case, pattern::AcronymPattern, ExtraConverter};
+    ///
+    /// let conv = ExtraConverter::new()
+    ///     .from_case(case::SENTENCE_PRESERVING)
+    ///     .to_case(case::SENTENCE_PRESERVING)
+    ///     .set_pattern(
+    ///         AcronymPattern::sentence(Acronyms::new().with("Kubernetes").with("SDK"))
+    ///             .keep_uppercase(true),
+    ///     );
+    /// assert_eq!(conv.convert("Deploy kubernetes Sdk"), "Deploy Kubernetes SDK");
+    /// ```
+    pub const SENTENCE_PRESERVING: Case = Case::Custom {
+        boundaries: &[Boundary::Space],
+        pattern: pattern::SENTENCE_PRESERVING,
+        delim: " ",
+    };
+
+    /// Dot case strings are delimited by periods `.` and are all lowercase.
+    /// * Boundaries: Period `"."`
+    /// * Pattern: [Lowercase](Pattern::Lowercase)
+    /// * Delimeter: Period `"."`
+    ///
+    /// ```
+    /// use convert_case::Casing;
+    /// use convert_case_extras::case;
+    /// assert_eq!("My variable NAME".to_case(case::DOT), "my.variable.name");
+    /// ```
+    pub const DOT: Case = Case::Custom {
+        boundaries: &[Boundary::from_delim(".")],
+        pattern: Pattern::Lowercase,
+        delim: ".",
+    };
+
+    /// Returns the cases in this crate that produce the same output every time.  This
+    /// excludes the random cases.
+    ///
+    /// Converting a string from one of these cases into the same case leaves it
+    /// unchanged, which a random case would not.
+    /// ```
+    /// use convert_case::Casing;
+    /// use convert_case_extras::case;
+    ///
+    /// for &case in case::deterministic_cases() {
+    ///     let converted = "My variable NAME".to_case(case);
+    ///     assert_eq!(converted.from_case(case).to_case(case), converted);
+    /// }
+    /// ```
+    pub fn deterministic_cases() -> &'static [Case<'static>] {
+        &[
+            TOGGLE,
+            ALTERNATING,
+            TITLE_AP,
+            TITLE_CHICAGO,
+            TITLE_APA,
+            TITLE_MLA,
+            SENTENCE_PRESERVING,
+            DOT,
+            #[cfg(feature = "unicode-segmentation")]
+            GRAPHEME_TOGGLE,
+            #[cfg(feature = "unicode-segmentation")]
+            GRAPHEME_ALTERNATING,
+        ]
+    }
+
+    /// Every case that can be looked up with [`by_name`], with its name.
+    const BY_NAME: &[(&str, Case<'static>)] = &[
+        ("snake", Case::Snake),
+        ("constant", Case::Constant),
+        ("upper-snake", Case::UpperSnake),
+        ("ada", Case::Ada),
+        ("kebab", Case::Kebab),
+        ("cobol", Case::Cobol),
+        ("upper-kebab", Case::UpperKebab),
+        ("train", Case::Train),
+        ("flat", Case::Flat),
+        ("upper-flat", Case::UpperFlat),
+        ("pascal", Case::Pascal),
+        ("upper-camel", Case::UpperCamel),
+        ("camel", Case::Camel),
+        ("lower", Case::Lower),
+        ("upper", Case::Upper),
+        ("title", Case::Title),
+        ("sentence", Case::Sentence),
+        ("toggle", TOGGLE),
+        ("alternating", ALTERNATING),
+        ("title-ap", TITLE_AP),
+        ("title-chicago", TITLE_CHICAGO),
+        ("title-apa", TITLE_APA),
+        ("title-mla", TITLE_MLA),
+        ("sentence-preserving", SENTENCE_PRESERVING),
+        ("dot", DOT),
+        #[cfg(feature = "unicode-segmentation")]
+        ("grapheme-toggle", GRAPHEME_TOGGLE),
+        #[cfg(feature = "unicode-segmentation")]
+        ("grapheme-alternating", GRAPHEME_ALTERNATING),
+    ];
+
+    /// Looks up a deterministic case by its name in kebab case, like `"snake"`,
+    /// `"upper-kebab"` or `"title-chicago"`.
+    ///
+    /// This covers the cases of `convert_case` and the [deterministic
+    /// cases](deterministic_cases) of this crate.  The names `"toggle"` and `"alternating"`
+    /// refer to [`TOGGLE`] and [`ALTERNATING`] from this crate.
+    /// ```
+    /// use convert_case::{Case, Casing};
+    /// use convert_case_extras::case;
+    ///
+    /// assert_eq!(case::by_name("upper-snake"), Some(Case::UpperSnake));
+    /// assert_eq!("my_var".to_case(case::by_name("dot").unwrap()), "my.var");
+    /// assert_eq!(case::by_name("random"), None);
+    /// ```
+    pub fn by_name(name: &str) -> Option<Case<'static>> {
+        BY_NAME
+            .iter()
+            .find(|(n, _)| *n == name)
+            .map(|&(_, case)| case)
+    }
+
+    /// The names accepted by [`by_name`].
+    pub fn names() -> impl Iterator<Item = &'static str> {
+        BY_NAME.iter().map(|&(name, _)| name)
+    }
+
     /// Random case strings are delimited by spaces and characters are
     /// randomly upper case or lower case.
     ///
     /// This uses the `rand` crate
-    /// and is only available with the "random" feature.
+    /// and is only available with the "random" and "std" features.
     /// * Boundaries: [Space](Boundary::Space)
     /// * Pattern: [Random](pattern::RANDOM)
     /// * Delimeter: Space `" "`
@@ -223,8 +1486,7 @@ pub mod case {
     /// "My variable NAME".to_case(case::RANDOM);
     /// // "My vaRIAbLE nAme"
     /// ```
-    #[cfg(any(doc, feature = "random"))]
-    #[cfg(feature = "random")]
+    #[cfg(all(feature = "random", feature = "std"))]
     pub const RANDOM: Case = Case::Custom {
         boundaries: &[Boundary::Space],
         pattern: pattern::RANDOM,
@@ -236,7 +1498,7 @@ pub mod case {
     /// case or upper case letters in a row.
     ///
     /// This uses the `rand` crate and is
-    /// only available with the "random" feature.
+    /// only available with the "random" and "std" features.
     /// * Boundaries: [Space](Boundary::Space)
     /// * Pattern: [Pseudo random](pattern::PSEUDO_RANDOM)
     /// * Delimeter: Space `" "`
@@ -247,13 +1509,81 @@ pub mod case {
     /// let new = "My variable NAME".to_case(case::PSEUDO_RANDOM);
     /// ```
     /// String `new` could be "mY vArIAblE NamE" for example.
-    #[cfg(any(doc, feature = "random"))]
-    #[cfg(feature = "random")]
+    #[cfg(all(feature = "random", feature = "std"))]
     pub const PSEUDO_RANDOM: Case = Case::Custom {
         boundaries: &[Boundary::Space],
         pattern: pattern::PSEUDO_RANDOM,
         delim: " ",
     };
+
+    /// A seedable equivalent of [`RANDOM`] and [`PSEUDO_RANDOM`].
+    ///
+    /// Strings are delimited by spaces and each letter is cased by a
+    /// [`RandomPattern`](pattern::RandomPattern), so the same seed always produces
+    /// the same output.  Like [`to_case`](convert_case::Casing::to_case),
+    /// [`convert`](RandomCase::convert) splits the input on the default boundaries.
+    ///
+    /// This uses the `rand` crate and is only available with the "random" feature.
+    /// * Boundaries: [defaults](Boundary::defaults)
+    /// * Pattern: [RandomPattern](pattern::RandomPattern)
+    /// * Delimeter: Space `" "`
+    ///
+    /// ```
+    /// use convert_case_extras::case::RandomCase;
+    ///
+    /// assert_eq!(
+    ///     RandomCase::random(42).convert("my_variable_name"),
+    ///     RandomCase::random(42).convert("my_variable_name"),
+    /// );
+    /// assert_eq!(
+    ///     RandomCase::pseudo_random(42).convert("myVariableName").to_lowercase(),
+    ///     "my variable name",
+    /// );
+    /// ```
+    #[cfg(feature = "random")]
+    #[derive(Debug, Clone)]
+    pub struct RandomCase<R = rand_chacha::ChaCha8Rng> {
+        pattern: pattern::RandomPattern<R>,
+    }
+
+    #[cfg(feature = "random")]
+    impl RandomCase {
+        /// Equivalent to [`RANDOM`] using a generator seeded with `seed`.
+        pub fn random(seed: u64) -> Self {
+            Self::from_pattern(pattern::RandomPattern::with_seed(seed))
+        }
+
+        /// Equivalent to [`PSEUDO_RANDOM`] using a generator seeded with `seed`.
+        pub fn pseudo_random(seed: u64) -> Self {
+            Self::from_pattern(pattern::RandomPattern::with_seed(seed).pseudo())
+        }
+    }
+
+    #[cfg(feature = "random")]
+    impl<R: RngCore> RandomCase<R> {
+        /// Uses the given pattern to case each letter.
+        pub fn from_pattern(pattern: pattern::RandomPattern<R>) -> Self {
+            RandomCase { pattern }
+        }
+
+        /// The boundaries used to split strings before they are converted.
+        pub fn boundaries(&self) -> &'static [Boundary] {
+            const DEFAULTS: [Boundary; 9] = Boundary::defaults();
+            &DEFAULTS
+        }
+
+        /// The delimeter used to join words.
+        pub fn delim(&self) -> &'static str {
+            " "
+        }
+
+        /// Splits `s` on the default boundaries and joins the randomly cased words
+        /// with spaces.
+        pub fn convert<T: AsRef<str>>(&self, s: T) -> String {
+            let words = convert_case::split(&s, self.boundaries());
+            self.pattern.mutate(&words).join(self.delim())
+        }
+    }
 }
 
 #[cfg(test)]
@@ -266,4 +1596,268 @@ mod test {
     fn toggle_case() {
         assert_eq!("test_toggle".to_case(case::TOGGLE), "tEST tOGGLE");
     }
+
+    #[test]
+    fn alternating_final_sigma() {
+        assert_eq!("ΑΒΓΔΣ".to_case(case::ALTERNATING), "αΒγΔς");
+        assert_eq!("ΑΒΓΣΔ".to_case(case::ALTERNATING), "αΒγΣδ");
+    }
+
+    #[test]
+    fn localized_toggle() {
+        use locale::Locale;
+
+        let conv = ExtraConverter::new()
+            .to_case(Case::Snake)
+            .set_pattern(pattern::Localized::toggle(Locale::Turkish));
+        assert_eq!(conv.convert("ilk İş"), "iLK_iŞ");
+        let conv = conv.set_pattern(pattern::Localized::toggle(Locale::Root));
+        assert_eq!(conv.convert("ilk İş"), "iLK_i\u{307}Ş");
+    }
+
+    #[test]
+    fn sentence_preserving() {
+        let convert = |s: &str| {
+            s.from_case(case::SENTENCE_PRESERVING)
+                .to_case(case::SENTENCE_PRESERVING)
+        };
+        assert_eq!(
+            convert("Fix GitHub Login For NASA"),
+            "Fix GitHub login for NASA"
+        );
+        assert_eq!(convert("iOS: add JSON export"), "iOS: add JSON export");
+        assert_eq!(convert("ADD A TEST"), "ADD a TEST");
+        assert_eq!(convert("Add A Test For IDs"), "Add a test for IDs");
+        assert_eq!(convert("show macos user ids"), "Show macOS user IDs");
+        assert_eq!(convert("ΑΒΓ ΔΕΖ"), "ΑΒΓ ΔΕΖ");
+        assert_eq!(convert(""), "");
+
+        // Unlike sentence case, which loses every name
+        assert_eq!(
+            "Fix GitHub Login For NASA"
+                .from_case(Case::Sentence)
+                .to_case(Case::Sentence),
+            "Fix github login for nasa"
+        );
+    }
+
+    #[test]
+    fn keep_uppercase_camel() {
+        let conv = ExtraConverter::new()
+            .from_case(Case::Snake)
+            .to_case(Case::Camel)
+            .set_pattern(
+                pattern::AcronymPattern::camel(acronym::Acronyms::new()).keep_uppercase(true),
+            );
+        assert_eq!(conv.convert("NASA_CLI_tool"), "nasaCLITool");
+    }
+
+    #[test]
+    fn alternating_options() {
+        use pattern::Alternating;
+
+        let a = Alternating::new();
+        let table = [
+            (a, "aBc D'eF"),
+            (a.start_upper(true), "AbC d'Ef"),
+            (a.reset_per_word(true), "aBc d'Ef"),
+            (a.start_upper(true).reset_per_word(true), "AbC D'eF"),
+            (a.count_non_letters(true), "aBc D'Ef"),
+            (a.count_non_letters(true).start_upper(true), "AbC d'eF"),
+            (a.count_non_letters(true).reset_per_word(true), "aBc d'eF"),
+            (
+                a.count_non_letters(true)
+                    .reset_per_word(true)
+                    .start_upper(true),
+                "AbC D'Ef",
+            ),
+            (a.period(3), "abC d'eF"),
+            (a.period(3).start_upper(true), "Abc D'ef"),
+            (
+                a.period(3).reset_per_word(true).count_non_letters(true),
+                "abC d'Ef",
+            ),
+            (
+                a.period(4).start_upper(true).count_non_letters(true),
+                "Abc d'ef",
+            ),
+        ];
+        for (alternating, expected) in table {
+            let conv = ExtraConverter::new()
+                .to_case(Case::Lower)
+                .set_pattern(alternating);
+            assert_eq!(conv.convert("ABC D'EF"), expected, "{:?}", alternating);
+            assert_eq!(Alternating::from_bits(alternating.to_bits()), alternating);
+        }
+    }
+
+    #[test]
+    fn alternating_cases() {
+        use pattern::Alternating;
+
+        const DEFAULT: Case = case::alternating::<{ Alternating::new().to_bits() }>();
+        const UPPER_RESET: Case = case::alternating::<
+            {
+                Alternating::new()
+                    .start_upper(true)
+                    .reset_per_word(true)
+                    .to_bits()
+            },
+        >();
+        const THIRDS: Case = case::alternating::<
+            {
+                Alternating::new()
+                    .period(3)
+                    .count_non_letters(true)
+                    .to_bits()
+            },
+        >();
+
+        for s in ["abc d'ef", "ΑΒΓΔΣ", "My variable NAME"] {
+            assert_eq!(s.to_case(DEFAULT), s.to_case(case::ALTERNATING));
+        }
+        assert_eq!("abc d'ef".to_case(UPPER_RESET), "AbC D'eF");
+        assert_eq!("abc d'ef".to_case(THIRDS), "abC d'Ef");
+        assert_eq!(
+            pattern::alternating::<{ Alternating::new().period(3).to_bits() }>()
+                .mutate(&["abc", "d'ef"]),
+            ["abC", "d'eF"]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn alternating_period_too_short() {
+        pattern::Alternating::new().period(1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn alternating_invalid_bits() {
+        pattern::Alternating::from_bits(u32::MAX);
+    }
+
+    #[test]
+    fn alternating_locale() {
+        use pattern::Alternating;
+
+        const TURKISH: Case =
+            case::alternating::<{ Alternating::new().locale(Locale::Turkish).to_bits() }>();
+        assert_eq!("iiii".to_case(TURKISH), "iİiİ");
+        let greek = Alternating::new().locale(Locale::Greek);
+        assert_eq!(greek.mutate(&["άάάά"]), ["άΑάΑ"]);
+        assert_eq!(Alternating::from_bits(greek.to_bits()), greek);
+        assert!(std::panic::catch_unwind(|| Alternating::from_bits(2 << 6 | 5 << 3)).is_err());
+    }
+
+    #[cfg(feature = "unicode-segmentation")]
+    #[test]
+    fn grapheme_patterns_nfc_nfd() {
+        // Precomposed (NFC) and decomposed (NFD) forms alternate the same way
+        let alternating = [
+            ("\u{e9}t\u{e9} caf\u{e9}", "\u{e9}T\u{e9} CaF\u{e9}"),
+            (
+                "e\u{301}te\u{301} cafe\u{301}",
+                "e\u{301}Te\u{301} CaFe\u{301}",
+            ),
+            ("\u{1fb3}\u{3b2}\u{3b3}", "\u{1fb3}\u{392}\u{3b3}"),
+            (
+                "\u{3b1}\u{345}\u{3b2}\u{3b3}",
+                "\u{3b1}\u{345}\u{392}\u{3b3}",
+            ),
+            ("\u{1e69}\u{1e0d}", "\u{1e69}\u{1e0c}"),
+            ("s\u{323}\u{307}d\u{323}", "s\u{323}\u{307}D\u{323}"),
+        ];
+        for (input, expected) in alternating {
+            assert_eq!(input.to_case(case::GRAPHEME_ALTERNATING), expected);
+        }
+
+        // The ypogegrammeni is a lowercase letter on its own, so it takes a turn
+        assert_eq!(
+            "\u{3b1}\u{345}\u{3b2}\u{3b3}".to_case(case::ALTERNATING),
+            "\u{3b1}\u{345}\u{3b2}\u{393}"
+        );
+
+        let toggle = [
+            ("\u{e9}cole", "\u{e9}COLE"),
+            ("e\u{301}cole", "e\u{301}COLE"),
+            ("\u{1e69}a", "\u{1e69}A"),
+            ("s\u{323}\u{307}a", "s\u{323}\u{307}A"),
+        ];
+        for (input, expected) in toggle {
+            assert_eq!(input.to_case(case::GRAPHEME_TOGGLE), expected);
+        }
+    }
+
+    #[cfg(feature = "unicode-segmentation")]
+    #[test]
+    fn grapheme_patterns_keep_clusters() {
+        // An emoji ZWJ sequence, a flag and a decomposed accent are never split
+        let s = "a\u{1f469}\u{200d}\u{1f4bb}b\u{1f1f3}\u{1f1f4}ce\u{301}d";
+        assert_eq!(
+            s.to_case(case::GRAPHEME_ALTERNATING),
+            "a\u{1f469}\u{200d}\u{1f4bb}B\u{1f1f3}\u{1f1f4}cE\u{301}d"
+        );
+        assert_eq!(
+            s.to_case(case::GRAPHEME_TOGGLE),
+            "a\u{1f469}\u{200d}\u{1f4bb}B\u{1f1f3}\u{1f1f4}CE\u{301}D"
+        );
+        assert_eq!(
+            "e\u{301}cole".to_case(case::GRAPHEME_TOGGLE),
+            "e\u{301}COLE"
+        );
+    }
+
+    #[cfg(feature = "random")]
+    #[test]
+    fn seeded_random_is_reproducible() {
+        let words = ["random", "letters", "every", "time"];
+        let a = pattern::RandomPattern::with_seed(1);
+        let b = pattern::RandomPattern::with_seed(1);
+        for _ in 0..10 {
+            assert_eq!(a.mutate(&words), b.mutate(&words));
+        }
+    }
+
+    #[cfg(all(feature = "random", feature = "std"))]
+    #[test]
+    fn deterministic_cases_exclude_random() {
+        let cases = case::deterministic_cases();
+        assert!(!cases.contains(&case::RANDOM));
+        assert!(!cases.contains(&case::PSEUDO_RANDOM));
+    }
+
+    #[cfg(feature = "random")]
+    #[test]
+    fn seeded_random_golden() {
+        // Changes if an upgrade of rand or rand_chacha changes the generated stream
+        assert_eq!(
+            case::RandomCase::random(42).convert("my_variable_name"),
+            "mY vARiaBLe Name"
+        );
+        assert_eq!(
+            case::RandomCase::pseudo_random(42).convert("myVariableName"),
+            "mY VarIAbLe nAmE"
+        );
+    }
+
+    #[cfg(feature = "random")]
+    #[test]
+    fn seeded_random_advances() {
+        let pattern = pattern::RandomPattern::with_seed(1);
+        let first = pattern.mutate(&["thirty two letters long in total"]);
+        let second = pattern.mutate(&["thirty two letters long in total"]);
+        assert_ne!(first, second);
+    }
+
+    #[cfg(feature = "random")]
+    #[test]
+    fn seeded_pseudo_random_never_three_in_a_row() {
+        let case = case::RandomCase::pseudo_random(3);
+        for _ in 0..20 {
+            let s = case.convert("abcdefghijklmnopqrstuvwxyz");
+            let upper: Vec<bool> = s.chars().map(|c| c.is_uppercase()).collect();
+            assert!(upper.windows(3).all(|w| !(w[0] == w[1] && w[1] == w[2])));
+        }
+    }
 }
@@ -0,0 +1,304 @@
+//! Language-sensitive casing rules.
+//!
+//! Rust's `char::to_lowercase` and `char::to_uppercase` implement only the
+//! unconditional mappings from the Unicode character database.  The conditional
+//! mappings in [SpecialCasing.txt](https://www.unicode.org/Public/UCD/latest/ucd/SpecialCasing.txt)
+//! depend on the surrounding letters or on the language of the text, and are
+//! implemented here.
+//!
+//! ```
+//! use convert_case_extras::locale::Locale;
+//!
+//! assert_eq!(Locale::Turkish.to_uppercase("istanbul"), "İSTANBUL");
+//! assert_eq!(Locale::Turkish.to_lowercase("DIŞ"), "dış");
+//! assert_eq!(Locale::Root.to_lowercase("ΟΔΟΣ"), "οδος");
+//! ```
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// A language whose casing rules differ from the default Unicode mappings.
+///
+/// Every locale applies the language-independent Final_Sigma rule: a capital
+/// sigma at the end of a word lowercases to `ς` instead of `σ`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Locale {
+    /// The default Unicode mappings.
+    #[default]
+    Root,
+
+    /// Turkish (`tr`).  Dotted and dotless i are distinct letters: `i` uppercases to `İ`
+    /// and `I` lowercases to `ı`.
+    Turkish,
+
+    /// Azeri (`az`).  Uses the same rules as Turkish.
+    Azeri,
+
+    /// Lithuanian (`lt`).  Lowercase `i` and `j` keep an explicit dot above when followed
+    /// by another accent, and lose it when uppercased.
+    Lithuanian,
+
+    /// Greek (`el`).  Accents are removed when a letter is uppercased.
+    Greek,
+}
+
+const COMBINING_DOT_ABOVE: char = '\u{307}';
+
+impl Locale {
+    /// Lowercases every letter in `s`.
+    pub fn to_lowercase(self, s: &str) -> String {
+        self.case_chars(s, |_| false)
+    }
+
+    /// Uppercases every letter in `s`.
+    pub fn to_uppercase(self, s: &str) -> String {
+        self.case_chars(s, |_| true)
+    }
+
+    /// Uppercases the first character of `s` and lowercases the rest.
+    pub fn to_capital(self, s: &str) -> String {
+        let mut first = true;
+        self.case_chars(s, |_| core::mem::take(&mut first))
+    }
+
+    /// Lowercases the first character of `s` and uppercases the rest.
+    pub fn to_toggle(self, s: &str) -> String {
+        let mut first = true;
+        self.case_chars(s, |_| !core::mem::take(&mut first))
+    }
+
+    /// Cases each character of `s` in order, uppercasing it when `upper` returns true
+    /// and lowercasing it otherwise.
+    ///
+    /// `upper` is called exactly once for every character, including ones that have no
+    /// case.  Context-sensitive rules are applied using the surrounding characters of `s`.
+    pub(crate) fn case_chars<F>(self, s: &str, mut upper: F) -> String
+    where
+        F: FnMut(char) -> bool,
+    {
+        let chars: Vec<char> = s.chars().collect();
+        let mut out = String::with_capacity(s.len());
+
+        // Whether the previous character was uppercased
+        let mut prev_upper = false;
+        // Whether a following combining dot above was absorbed into the previous letter
+        let mut absorb_dot = false;
+
+        for (i, &c) in chars.iter().enumerate() {
+            let to_upper = upper(c);
+
+            if is_combining_mark(c) {
+                let prev = i.checked_sub(1).map(|j| chars[j]);
+                let drop = match self {
+                    _ if c == COMBINING_DOT_ABOVE && absorb_dot => true,
+                    // After_Soft_Dotted: the dot is implied by the lowercase letter
+                    Locale::Lithuanian => {
+                        c == COMBINING_DOT_ABOVE && prev_upper && prev.is_some_and(is_soft_dotted)
+                    }
+                    // Accents are dropped from uppercase Greek letters
+                    Locale::Greek => is_greek_accent(c) && prev_upper && prev.is_some_and(is_greek),
+                    _ => false,
+                };
+                absorb_dot = false;
+                if !drop {
+                    out.push(c);
+                }
+                continue;
+            }
+
+            absorb_dot = false;
+            prev_upper = to_upper;
+            if to_upper {
+                self.push_upper(c, &mut out);
+            } else {
+                absorb_dot = self.push_lower(&chars, i, &mut out);
+            }
+        }
+
+        out
+    }
+
+    fn push_upper(self, c: char, out: &mut String) {
+        match (self, c) {
+            (Locale::Turkish | Locale::Azeri, 'i') => out.push('İ'),
+            (Locale::Greek, c) => out.extend(strip_greek_tonos(c).to_uppercase()),
+            (_, c) => out.extend(c.to_uppercase()),
+        }
+    }
+
+    /// Pushes the lowercase form of `chars[i]`.  Returns true if a combining dot
+    /// above that immediately follows should be removed.
+    fn push_lower(self, chars: &[char], i: usize, out: &mut String) -> bool {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+        let more_above = next.is_some_and(is_combining_above);
+
+        match (self, c) {
+            (_, 'Σ') if is_final_sigma(chars, i) => out.push('ς'),
+            (Locale::Turkish | Locale::Azeri, 'İ') => out.push('i'),
+            (Locale::Turkish | Locale::Azeri, 'I') => {
+                // Before_Dot: an I followed by a dot above is a dotted i
+                if next == Some(COMBINING_DOT_ABOVE) {
+                    out.push('i');
+                    return true;
+                }
+                out.push('ı');
+            }
+            (Locale::Lithuanian, 'I') if more_above => out.push_str("i\u{307}"),
+            (Locale::Lithuanian, 'J') if more_above => out.push_str("j\u{307}"),
+            (Locale::Lithuanian, 'Į') if more_above => out.push_str("į\u{307}"),
+            (Locale::Lithuanian, 'Ì') => out.push_str("i\u{307}\u{300}"),
+            (Locale::Lithuanian, 'Í') => out.push_str("i\u{307}\u{301}"),
+            (Locale::Lithuanian, 'Ĩ') => out.push_str("i\u{307}\u{303}"),
+            (_, c) => out.extend(c.to_lowercase()),
+        }
+
+        false
+    }
+}
+
+/// Final_Sigma: preceded by a cased letter and not followed by one, ignoring
+/// case-ignorable characters in between.
+fn is_final_sigma(chars: &[char], i: usize) -> bool {
+    final_sigma(
+        chars[..i].iter().rev().copied(),
+        chars[i + 1..].iter().copied(),
+    )
+}
+
+/// The Final_Sigma condition, given the characters before a sigma in reverse order
+/// and the characters after it.
+pub(crate) fn final_sigma<B, A>(mut before: B, mut after: A) -> bool
+where
+    B: Iterator<Item = char>,
+    A: Iterator<Item = char>,
+{
+    let before = before
+        .find(|c| !is_case_ignorable(*c))
+        .is_some_and(is_cased);
+    let after = after.find(|c| !is_case_ignorable(*c)).is_some_and(is_cased);
+    before && !after
+}
+
+fn is_cased(c: char) -> bool {
+    c.is_lowercase() || c.is_uppercase()
+}
+
+fn is_case_ignorable(c: char) -> bool {
+    is_combining_mark(c) || matches!(c, '\'' | '.' | ':' | '^' | '`' | '·' | '\u{ad}' | '’')
+}
+
+fn is_combining_mark(c: char) -> bool {
+    matches!(c,
+        '\u{300}'..='\u{36f}'
+        | '\u{1ab0}'..='\u{1aff}'
+        | '\u{1dc0}'..='\u{1dff}'
+        | '\u{20d0}'..='\u{20ff}'
+        | '\u{fe20}'..='\u{fe2f}')
+}
+
+/// Marks with canonical combining class 230 in the Combining Diacritical Marks block.
+fn is_combining_above(c: char) -> bool {
+    matches!(c,
+        '\u{300}'..='\u{314}'
+        | '\u{33d}'..='\u{344}'
+        | '\u{346}'
+        | '\u{34a}'..='\u{34c}'
+        | '\u{350}'..='\u{352}'
+        | '\u{357}'
+        | '\u{35b}'
+        | '\u{363}'..='\u{36f}')
+}
+
+fn is_soft_dotted(c: char) -> bool {
+    matches!(c, 'i' | 'j' | 'į' | 'ɨ' | 'ʝ' | 'ⁱ' | 'ᶤ' | 'ḭ' | 'ị')
+}
+
+fn is_greek(c: char) -> bool {
+    matches!(c, '\u{370}'..='\u{3ff}' | '\u{1f00}'..='\u{1fff}')
+}
+
+fn is_greek_accent(c: char) -> bool {
+    matches!(c, '\u{301}' | '\u{313}' | '\u{314}' | '\u{342}' | '\u{343}')
+}
+
+fn strip_greek_tonos(c: char) -> char {
+    match c {
+        'ά' | 'Ά' => 'α',
+        'έ' | 'Έ' => 'ε',
+        'ή' | 'Ή' => 'η',
+        'ί' | 'Ί' => 'ι',
+        'ό' | 'Ό' => 'ο',
+        'ύ' | 'Ύ' => 'υ',
+        'ώ' | 'Ώ' => 'ω',
+        'ΐ' => 'ϊ',
+        'ΰ' => 'ϋ',
+        c => c,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn root_matches_std() {
+        for s in ["Hello World", "ǅemal", "straße", "İstanbul", "ΑΒΓ"] {
+            assert_eq!(Locale::Root.to_lowercase(s), s.to_lowercase());
+            assert_eq!(Locale::Root.to_uppercase(s), s.to_uppercase());
+        }
+    }
+
+    #[test]
+    fn final_sigma() {
+        assert_eq!(Locale::Root.to_lowercase("ΟΔΟΣ"), "οδος");
+        assert_eq!(Locale::Root.to_lowercase("ΣΟΦΙΑ"), "σοφια");
+        assert_eq!(Locale::Root.to_lowercase("Σ"), "σ");
+        assert_eq!(Locale::Greek.to_lowercase("ΟΔΟΣ."), "οδος.");
+        assert_eq!(Locale::Turkish.to_lowercase("ΑΣ'Β"), "ασ'β");
+    }
+
+    #[test]
+    fn turkish() {
+        assert_eq!(Locale::Turkish.to_uppercase("iı"), "İI");
+        assert_eq!(Locale::Turkish.to_lowercase("İI"), "iı");
+        assert_eq!(Locale::Turkish.to_lowercase("I\u{307}"), "i");
+        assert_eq!(Locale::Turkish.to_capital("istanbul"), "İstanbul");
+        assert_eq!(Locale::Turkish.to_toggle("ISPARTA"), "ıSPARTA");
+        assert_eq!(Locale::Azeri.to_uppercase("iki"), "İKİ");
+    }
+
+    #[test]
+    fn lithuanian() {
+        assert_eq!(
+            Locale::Lithuanian.to_lowercase("I\u{300}"),
+            "i\u{307}\u{300}"
+        );
+        assert_eq!(
+            Locale::Lithuanian.to_lowercase("J\u{301}"),
+            "j\u{307}\u{301}"
+        );
+        assert_eq!(
+            Locale::Lithuanian.to_lowercase("Į\u{303}"),
+            "į\u{307}\u{303}"
+        );
+        assert_eq!(
+            Locale::Lithuanian.to_lowercase("ÌÍĨ"),
+            "i\u{307}\u{300}i\u{307}\u{301}i\u{307}\u{303}"
+        );
+        assert_eq!(Locale::Lithuanian.to_lowercase("IJ"), "ij");
+        assert_eq!(
+            Locale::Lithuanian.to_uppercase("i\u{307}\u{300}"),
+            "I\u{300}"
+        );
+        assert_eq!(Locale::Root.to_uppercase("i\u{307}"), "I\u{307}");
+    }
+
+    #[test]
+    fn greek() {
+        assert_eq!(Locale::Greek.to_uppercase("άέήίόύώ"), "ΑΕΗΙΟΥΩ");
+        assert_eq!(Locale::Greek.to_uppercase("α\u{301}"), "Α");
+        assert_eq!(Locale::Greek.to_capital("όροι"), "Οροι");
+        assert_eq!(Locale::Root.to_uppercase("ά"), "Ά");
+    }
+}
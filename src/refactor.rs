@@ -0,0 +1,583 @@
+//! Renaming identifiers in source code from one case to another.
+//!
+//! Source files are tokenized just enough to find their identifiers.  String and
+//! character literals, comments, keywords and any identifiers on an allowlist are left
+//! as they are.  An identifier is renamed only when it is already in the source case,
+//! meaning converting it from that case into itself doesn't change it, and its new name
+//! isn't a keyword.  Rather than changing files, [`Renamer::diff`] describes the changes
+//! as a unified diff that can be reviewed and applied with `patch` or `git apply`.
+//!
+//! ```
+//! use convert_case::Case;
+//! use convert_case_extras::lang::Language;
+//! use convert_case_extras::refactor::Renamer;
+//!
+//! let renamer = Renamer::new(Language::Python, Case::Camel, Case::Snake);
+//! let source = "def getUser(userId):  # fetch userId\n    return db.find(\"userId\", userId)\n";
+//! assert_eq!(
+//!     renamer.rename(source),
+//!     "def get_user(user_id):  # fetch userId\n    return db.find(\"userId\", user_id)\n",
+//! );
+//! ```
+//!
+//! The tokenizer doesn't know about types or scopes, so methods and properties of
+//! libraries, like `getElementById` in JavaScript, are renamed too unless they are
+//! allowed with [`Renamer::allow`].  The expressions inside JavaScript template literals
+//! and Python f-strings are treated as part of the string.
+
+use alloc::collections::BTreeSet;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use convert_case::{Case, Casing};
+
+use crate::lang::Language;
+
+/// The languages whose source code can be tokenized, and so whose identifiers can be
+/// renamed.
+pub const LANGUAGES: &[Language] = &[Language::Rust, Language::JavaScript, Language::Python];
+
+/// Names that JavaScript doesn't reserve, but that are contextual keywords or global
+/// values, and so are never renamed either.
+const JAVASCRIPT_CONTEXTUAL: &[&str] = &[
+    "Infinity",
+    "NaN",
+    "arguments",
+    "async",
+    "from",
+    "get",
+    "of",
+    "set",
+    "undefined",
+];
+
+/// JavaScript keywords after which a `/` starts a regular expression instead of dividing.
+const JAVASCRIPT_BEFORE_REGEX: &[&str] = &[
+    "await",
+    "case",
+    "delete",
+    "do",
+    "else",
+    "in",
+    "instanceof",
+    "new",
+    "of",
+    "return",
+    "throw",
+    "typeof",
+    "void",
+    "yield",
+];
+
+/// Renames the identifiers of source code from one case into another.
+/// ```
+/// use convert_case::Case;
+/// use convert_case_extras::lang::Language;
+/// use convert_case_extras::refactor::Renamer;
+///
+/// let renamer = Renamer::new(Language::JavaScript, Case::Camel, Case::Snake)
+///     .allow(&["getElementById"]);
+/// assert_eq!(
+///     renamer.rename("let myDiv = document.getElementById('myDiv'); // myDiv"),
+///     "let my_div = document.getElementById('myDiv'); // myDiv",
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct Renamer<'b> {
+    language: Language,
+    from: Case<'b>,
+    to: Case<'b>,
+    allowed: BTreeSet<String>,
+}
+
+impl<'b> Renamer<'b> {
+    /// Creates a renamer for identifiers of `language` in the `from` case, which renames
+    /// them into the `to` case.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `language` isn't one of the [`LANGUAGES`] that can be tokenized.
+    pub fn new(language: Language, from: Case<'b>, to: Case<'b>) -> Self {
+        assert!(
+            LANGUAGES.contains(&language),
+            "can't rename identifiers in {:?}",
+            language
+        );
+        Renamer {
+            language,
+            from,
+            to,
+            allowed: BTreeSet::new(),
+        }
+    }
+
+    /// Adds identifiers to leave as they are.
+    pub fn allow(mut self, idents: &[&str]) -> Self {
+        self.allowed.extend(idents.iter().map(ToString::to_string));
+        self
+    }
+
+    /// Returns the new name of `ident`, or `None` if it is left as it is.
+    /// ```
+    /// use convert_case::Case;
+    /// use convert_case_extras::lang::Language;
+    /// use convert_case_extras::refactor::Renamer;
+    ///
+    /// let renamer = Renamer::new(Language::Rust, Case::Camel, Case::Snake);
+    /// assert_eq!(renamer.rename_ident("parseInput"), Some("parse_input".to_string()));
+    /// assert_eq!(renamer.rename_ident("ParseError"), None);
+    /// assert_eq!(renamer.rename_ident("input"), None);
+    /// ```
+    pub fn rename_ident(&self, ident: &str) -> Option<String> {
+        if is_keyword(self.language, ident) || self.allowed.contains(ident) {
+            return None;
+        }
+        if ident.from_case(self.from).to_case(self.from) != ident {
+            return None;
+        }
+        let renamed = ident.from_case(self.from).to_case(self.to);
+        if renamed == ident || is_keyword(self.language, &renamed) {
+            return None;
+        }
+        Some(renamed)
+    }
+
+    /// Returns `source` with its identifiers renamed.
+    pub fn rename(&self, source: &str) -> String {
+        let mut renamed = String::with_capacity(source.len());
+        let mut last = 0;
+        for span in identifiers(self.language, source) {
+            if let Some(ident) = self.rename_ident(&source[span.clone()]) {
+                renamed.push_str(&source[last..span.start]);
+                renamed.push_str(&ident);
+                last = span.end;
+            }
+        }
+        renamed.push_str(&source[last..]);
+        renamed
+    }
+
+    /// Renames the identifiers of `source`, and returns the changes as a unified diff of
+    /// the file at `path`.  The diff is empty when nothing is renamed.
+    /// ```
+    /// use convert_case::Case;
+    /// use convert_case_extras::lang::Language;
+    /// use convert_case_extras::refactor::Renamer;
+    ///
+    /// let renamer = Renamer::new(Language::Rust, Case::Camel, Case::Snake);
+    /// let diff = renamer.diff("src/main.rs", "fn main() {\n    let myVar = 1;\n}\n");
+    /// assert_eq!(diff, "\
+    /// --- a/src/main.rs
+    /// +++ b/src/main.rs
+    /// @@ -1,3 +1,3 @@
+    ///  fn main() {
+    /// -    let myVar = 1;
+    /// +    let my_var = 1;
+    ///  }
+    /// ");
+    /// ```
+    pub fn diff(&self, path: &str, source: &str) -> String {
+        line_diff(path, source, &self.rename(source))
+    }
+}
+
+/// True if `word` is a keyword of `language`, or a name JavaScript treats like one.
+fn is_keyword(language: Language, word: &str) -> bool {
+    language.is_keyword(word)
+        || (language == Language::JavaScript && JAVASCRIPT_CONTEXTUAL.contains(&word))
+}
+
+/// The number of unchanged lines shown around each change in a diff.
+const CONTEXT: usize = 3;
+
+/// A unified diff of two texts with the same number of lines, where each line of `old`
+/// corresponds to the line of `new` at the same position.  This holds for renames, since
+/// identifiers never contain line breaks.
+fn line_diff(path: &str, old: &str, new: &str) -> String {
+    let old: Vec<&str> = old.split_inclusive('\n').collect();
+    let new: Vec<&str> = new.split_inclusive('\n').collect();
+    debug_assert_eq!(old.len(), new.len());
+
+    let changed: Vec<usize> = (0..old.len()).filter(|&i| old[i] != new[i]).collect();
+    let mut diff = String::new();
+    if changed.is_empty() {
+        return diff;
+    }
+    diff.push_str(&format!("--- a/{}\n+++ b/{}\n", path, path));
+
+    let mut i = 0;
+    while i < changed.len() {
+        // Changes whose context would touch belong to the same hunk
+        let mut j = i;
+        while j + 1 < changed.len() && changed[j + 1] - changed[j] <= 2 * CONTEXT + 1 {
+            j += 1;
+        }
+        let start = changed[i].saturating_sub(CONTEXT);
+        let end = (changed[j] + CONTEXT + 1).min(old.len());
+        diff.push_str(&format!(
+            "@@ -{},{} +{},{} @@\n",
+            start + 1,
+            end - start,
+            start + 1,
+            end - start
+        ));
+
+        let mut line = start;
+        while line < end {
+            if old[line] == new[line] {
+                push_line(&mut diff, ' ', old[line]);
+                line += 1;
+                continue;
+            }
+            let block = line..(line..end).find(|&l| old[l] == new[l]).unwrap_or(end);
+            for l in block.clone() {
+                push_line(&mut diff, '-', old[l]);
+            }
+            for l in block.clone() {
+                push_line(&mut diff, '+', new[l]);
+            }
+            line = block.end;
+        }
+        i = j + 1;
+    }
+    diff
+}
+
+fn push_line(diff: &mut String, marker: char, line: &str) {
+    diff.push(marker);
+    diff.push_str(line);
+    if !line.ends_with('\n') {
+        diff.push_str("\n\\ No newline at end of file\n");
+    }
+}
+
+/// The byte ranges of the identifiers in `source`, outside of comments and literals.
+fn identifiers(language: Language, source: &str) -> Vec<Range<usize>> {
+    let mut idents = Vec::new();
+    let mut i = 0;
+    // Whether the last token was a value, after which a `/` in JavaScript divides
+    // instead of starting a regular expression
+    let mut after_value = false;
+
+    // Rust and Python read a hashbang as an attribute and a comment
+    if language == Language::JavaScript && source.starts_with("#!") {
+        i = line_end(source, 0);
+    }
+
+    while let Some(c) = source[i..].chars().next() {
+        if c.is_whitespace() {
+            i += c.len_utf8();
+        } else if let Some(end) = comment_end(language, source, i) {
+            i = end;
+        } else if language.is_start(c) {
+            let end = ident_end(language, source, i);
+            let word = &source[i..end];
+            if let Some(end) = prefixed_literal_end(language, source, word, end) {
+                i = end;
+                after_value = true;
+            } else if language == Language::Rust
+                && word == "r"
+                && source[end..].starts_with('#')
+                && source[end + 1..]
+                    .chars()
+                    .next()
+                    .is_some_and(|c| language.is_start(c))
+            {
+                // A raw identifier, which is written so because it is a keyword
+                i = ident_end(language, source, end + 1);
+                after_value = true;
+            } else {
+                idents.push(i..end);
+                i = end;
+                after_value =
+                    !(language == Language::JavaScript && JAVASCRIPT_BEFORE_REGEX.contains(&word));
+            }
+        } else if c.is_ascii_digit() {
+            i = ident_end(language, source, i);
+            after_value = true;
+        } else if c == '"'
+            || (c == '\'' && language != Language::Rust)
+            || (c == '`' && language == Language::JavaScript)
+        {
+            i = string_end(language, source, i);
+            after_value = true;
+        } else if c == '\'' {
+            i = char_or_lifetime_end(source, i);
+            after_value = true;
+        } else if c == '/' && language == Language::JavaScript && !after_value {
+            i = regex_end(source, i);
+            after_value = true;
+        } else {
+            i += c.len_utf8();
+            after_value = matches!(c, ')' | ']' | '}');
+        }
+    }
+    idents
+}
+
+fn ident_end(language: Language, source: &str, start: usize) -> usize {
+    source[start..]
+        .char_indices()
+        .find(|&(_, c)| !language.is_part(c))
+        .map_or(source.len(), |(i, _)| start + i)
+}
+
+fn line_end(source: &str, start: usize) -> usize {
+    source[start..]
+        .find('\n')
+        .map_or(source.len(), |i| start + i)
+}
+
+/// The end of the comment starting at `start`, if there is one.
+fn comment_end(language: Language, source: &str, start: usize) -> Option<usize> {
+    let rest = &source[start..];
+    match language {
+        Language::Python => rest.starts_with('#').then(|| line_end(source, start)),
+        _ => {
+            if rest.starts_with("//") {
+                Some(line_end(source, start))
+            } else if rest.starts_with("/*") {
+                Some(block_comment_end(language, source, start))
+            } else {
+                None
+            }
+        }
+    }
+}
+
+/// The end of the block comment starting at `start`.  Rust's block comments nest.
+fn block_comment_end(language: Language, source: &str, start: usize) -> usize {
+    let bytes = source.as_bytes();
+    let mut depth = 0;
+    let mut i = start;
+    while i + 1 < bytes.len() {
+        match &bytes[i..i + 2] {
+            b"/*" if depth == 0 || language == Language::Rust => {
+                depth += 1;
+                i += 2;
+            }
+            b"*/" => {
+                depth -= 1;
+                i += 2;
+                if depth == 0 {
+                    return i;
+                }
+            }
+            _ => i += 1,
+        }
+    }
+    source.len()
+}
+
+/// The end of the text starting at `start` and ending with `quote`, where a backslash
+/// escapes the next character when `escapes` is set.
+fn quoted_end(source: &str, start: usize, quote: &str, escapes: bool) -> usize {
+    let mut chars = source[start..].char_indices();
+    while let Some((i, c)) = chars.next() {
+        if escapes && c == '\\' {
+            chars.next();
+        } else if source[start + i..].starts_with(quote) {
+            return start + i + quote.len();
+        }
+    }
+    source.len()
+}
+
+/// The end of the string literal whose opening quote is at `start`.
+fn string_end(language: Language, source: &str, start: usize) -> usize {
+    let rest = &source[start..];
+    let quote = if language == Language::Python
+        && (rest.starts_with("\"\"\"") || rest.starts_with("'''"))
+    {
+        &rest[..3]
+    } else {
+        &rest[..1]
+    };
+    quoted_end(source, start + quote.len(), quote, true)
+}
+
+/// The end of the literal starting with the identifier `word` that ends at `end`, like
+/// `r"..."` in Rust or `f'...'` in Python, if `word` is such a prefix.
+fn prefixed_literal_end(language: Language, source: &str, word: &str, end: usize) -> Option<usize> {
+    let next = source[end..].chars().next()?;
+    match language {
+        Language::Rust => match (word, next) {
+            ("b" | "c", '"') => Some(string_end(language, source, end)),
+            ("b", '\'') => Some(quoted_end(source, end + 1, "'", true)),
+            ("r" | "br" | "cr", '"' | '#') => {
+                let hashes = source[end..].len() - source[end..].trim_start_matches('#').len();
+                if !source[end + hashes..].starts_with('"') {
+                    return None;
+                }
+                let closing = format!("\"{}", "#".repeat(hashes));
+                Some(quoted_end(source, end + hashes + 1, &closing, false))
+            }
+            _ => None,
+        },
+        Language::Python => {
+            let prefixes = ["r", "u", "b", "f", "br", "rb", "fr", "rf"];
+            let is_prefix = prefixes.iter().any(|p| p.eq_ignore_ascii_case(word));
+            (is_prefix && matches!(next, '"' | '\'')).then(|| string_end(language, source, end))
+        }
+        _ => None,
+    }
+}
+
+/// The end of the character literal or lifetime starting at `start` in Rust.
+fn char_or_lifetime_end(source: &str, start: usize) -> usize {
+    let mut chars = source[start + 1..].chars();
+    match (chars.next(), chars.next()) {
+        (Some('\\'), _) => quoted_end(source, start + 1, "'", true),
+        (Some(c), Some('\'')) => start + 1 + c.len_utf8() + 1,
+        (Some(c), _) if Language::Rust.is_start(c) => ident_end(Language::Rust, source, start + 1),
+        _ => start + 1,
+    }
+}
+
+/// The end of the JavaScript regular expression starting at `start`, which ends at the
+/// first unescaped `/` outside of a character class, followed by its flags.
+fn regex_end(source: &str, start: usize) -> usize {
+    let mut in_class = false;
+    let mut chars = source[start + 1..].char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\\' => {
+                chars.next();
+            }
+            '[' => in_class = true,
+            ']' => in_class = false,
+            '/' if !in_class => return ident_end(Language::JavaScript, source, start + 1 + i + 1),
+            // An unterminated regular expression was probably a division after all
+            '\n' => return start + 1,
+            _ => {}
+        }
+    }
+    start + 1
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn idents(language: Language, source: &str) -> Vec<&str> {
+        identifiers(language, source)
+            .into_iter()
+            .map(|span| &source[span])
+            .collect()
+    }
+
+    #[test]
+    fn rust_tokens() {
+        let source = r##"
+            /* outer /* nestedComment */ stillComment */
+            fn fooBar<'myLife>(x: &'myLife str) -> char {
+                let s = r#"rawString "quoted" "#;
+                let b = b"byteString\"";
+                let r#type = '\'';
+                let c = 'c'; // lineComment
+                barBaz
+            }
+        "##;
+        assert_eq!(
+            idents(Language::Rust, source),
+            [
+                "fn", "fooBar", "x", "str", "char", "let", "s", "let", "b", "let", "let", "c",
+                "barBaz"
+            ]
+        );
+    }
+
+    #[test]
+    fn rust_escaped_chars() {
+        let source = r"let a = '\\'; let fooBar = 1; let b = '\''; let c = 'x';";
+        assert_eq!(
+            idents(Language::Rust, source),
+            ["let", "a", "let", "fooBar", "let", "b", "let", "c"]
+        );
+        let renamer = Renamer::new(Language::Rust, Case::Camel, Case::Snake);
+        assert_eq!(
+            renamer.rename(source),
+            r"let a = '\\'; let foo_bar = 1; let b = '\''; let c = 'x';"
+        );
+    }
+
+    #[test]
+    fn javascript_tokens() {
+        let source = "#!/usr/bin/env node\n\
+            const $el = `tpl ${notIdent}`; /* blockComment */\n\
+            let re = /a[/]bC/gi, half = total / count / 2;\n\
+            return /x/.test(someVal) ? \"dq\\\"str\" : 'sq';";
+        assert_eq!(
+            idents(Language::JavaScript, source),
+            ["const", "$el", "let", "re", "half", "total", "count", "return", "test", "someVal"]
+        );
+    }
+
+    #[test]
+    fn python_tokens() {
+        let source = "def f(x):  # comment\n    '''docString\n    'quote' '''\n    \
+            return rb'raw\\'' + F\"fmt {x}\" + x";
+        assert_eq!(
+            idents(Language::Python, source),
+            ["def", "f", "x", "return", "x"]
+        );
+    }
+
+    #[test]
+    fn rename_idents() {
+        let renamer = Renamer::new(Language::Python, Case::Snake, Case::Camel)
+            .allow(&["__init__", "keep_me"]);
+        assert_eq!(
+            renamer.rename("class A:\n    def __init__(self, my_arg, keep_me):\n        self.my_arg = my_arg\n"),
+            "class A:\n    def __init__(self, myArg, keep_me):\n        self.myArg = myArg\n"
+        );
+
+        let renamer = Renamer::new(Language::Rust, Case::Snake, Case::Pascal);
+        assert_eq!(renamer.rename_ident("self_"), None);
+        assert_eq!(renamer.rename_ident("HTTP_SERVER"), None);
+
+        let renamer = Renamer::new(Language::Rust, Case::Camel, Case::Snake);
+        assert_eq!(renamer.rename_ident("myHTTPServer"), None);
+        assert_eq!(renamer.rename_ident("é"), None);
+    }
+
+    #[test]
+    fn javascript_contextual_keywords() {
+        let renamer = Renamer::new(Language::JavaScript, Case::Camel, Case::Pascal);
+        assert_eq!(renamer.rename_ident("arguments"), None);
+        assert_eq!(renamer.rename_ident("delete"), None);
+        assert_eq!(renamer.rename_ident("myArgs"), Some("MyArgs".to_string()));
+    }
+
+    #[test]
+    #[should_panic]
+    fn unsupported_language() {
+        Renamer::new(Language::Go, Case::Camel, Case::Snake);
+    }
+
+    #[test]
+    fn diff_hunks() {
+        let renamer = Renamer::new(Language::JavaScript, Case::Camel, Case::Snake);
+        let source: String = (1..=12)
+            .map(|n| match n {
+                2 | 3 | 11 => format!("myVar; // {}\n", n),
+                _ => format!("// {}\n", n),
+            })
+            .collect();
+        assert_eq!(
+            renamer.diff("a.js", &source),
+            "--- a/a.js\n+++ b/a.js\n\
+             @@ -1,6 +1,6 @@\n // 1\n-myVar; // 2\n-myVar; // 3\n+my_var; // 2\n+my_var; // 3\n \
+             // 4\n // 5\n // 6\n\
+             @@ -8,5 +8,5 @@\n // 8\n // 9\n // 10\n-myVar; // 11\n+my_var; // 11\n // 12\n"
+        );
+        assert_eq!(renamer.diff("a.js", "line\nother\n"), "");
+        assert_eq!(
+            renamer.diff("a.js", "myVar"),
+            "--- a/a.js\n+++ b/a.js\n@@ -1,1 +1,1 @@\n-myVar\n\\ No newline at end of file\n\
+             +my_var\n\\ No newline at end of file\n"
+        );
+    }
+}
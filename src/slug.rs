@@ -0,0 +1,319 @@
+//! URL slugs: lowercase ASCII words joined by hyphens.
+//!
+//! Text is [transliterated](crate::transliterate) into ASCII, and every character that
+//! isn't a letter or digit separates words, except apostrophes, which are removed.  The
+//! words are then split like [`Case::Kebab`] and joined in kebab case.  Only available
+//! with the "slug" feature.
+//!
+//! ```
+//! use convert_case_extras::slug::{slugify, SlugRegistry, Slugger};
+//!
+//! assert_eq!(slugify("Crème Brûlée: A How-To"), "creme-brulee-a-how-to");
+//!
+//! let slugger = Slugger::new().max_len(20).stop_words(&["a", "the"]);
+//! assert_eq!(slugger.slug("The Lord of the Rings Extended Edition"), "lord-of-rings");
+//!
+//! let mut registry = SlugRegistry::new();
+//! assert_eq!(registry.slug("Release Notes"), "release-notes");
+//! assert_eq!(registry.slug("Release notes!"), "release-notes-2");
+//! ```
+
+use alloc::collections::BTreeSet;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use convert_case::Case;
+
+use crate::transliterate::transliterate;
+
+/// Common English articles, conjunctions and prepositions that can be left out of slugs
+/// with [`Slugger::stop_words`].
+pub const STOP_WORDS: &[&str] = &[
+    "a", "an", "and", "as", "at", "but", "by", "for", "from", "in", "into", "of", "on", "or",
+    "the", "to", "with",
+];
+
+/// Returns the slug of `s`, with no length limit or stop words.
+/// ```
+/// use convert_case_extras::slug::slugify;
+///
+/// assert_eq!(slugify("Hello, World!"), "hello-world");
+/// assert_eq!(slugify("Don't Panic"), "dont-panic");
+/// assert_eq!(slugify("  Top 10 -- Ελληνικά "), "top-10-ellinika");
+/// ```
+pub fn slugify(s: &str) -> String {
+    Slugger::new().slug(s)
+}
+
+/// Makes slugs with a maximum length and without stop words.
+///
+/// Slugs are truncated at a hyphen, so that they only contain whole words.  Only a
+/// single word longer than the maximum length is cut.  Stop words are removed unless
+/// every word is a stop word.
+/// ```
+/// use convert_case_extras::slug::{Slugger, STOP_WORDS};
+///
+/// let slugger = Slugger::new().max_len(16).stop_words(STOP_WORDS);
+/// assert_eq!(slugger.slug("A Tale of Two Cities"), "tale-two-cities");
+/// assert_eq!(slugger.slug("Supercalifragilistic"), "supercalifragili");
+/// assert_eq!(slugger.slug("To Be or Not to Be"), "be-not-be");
+/// assert_eq!(slugger.slug("The And"), "the-and");
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Slugger {
+    max_len: Option<usize>,
+    // Lowercased
+    stop_words: BTreeSet<String>,
+}
+
+impl Slugger {
+    /// Creates a slugger with no length limit or stop words.  This is the same as
+    /// `Default::default()`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Limits slugs to `len` bytes, which are also characters since slugs are ASCII.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len` is 0.
+    pub fn max_len(mut self, len: usize) -> Self {
+        assert!(len > 0, "slugs must be allowed at least 1 character");
+        self.max_len = Some(len);
+        self
+    }
+
+    /// Adds words to leave out of slugs, regardless of case.
+    pub fn stop_words(mut self, words: &[&str]) -> Self {
+        self.stop_words
+            .extend(words.iter().map(|word| word.to_lowercase()));
+        self
+    }
+
+    /// Returns the slug of `s`.
+    pub fn slug(&self, s: &str) -> String {
+        join_within(&self.words(s), self.max_len.unwrap_or(usize::MAX))
+    }
+
+    /// The words of the slug of `s`, before truncation.
+    fn words(&self, s: &str) -> Vec<String> {
+        let text: String = transliterate(s)
+            .chars()
+            .filter(|&c| c != '\'')
+            .map(|c| {
+                if c.is_ascii_alphanumeric() {
+                    c.to_ascii_lowercase()
+                } else {
+                    '-'
+                }
+            })
+            .collect();
+        let words: Vec<&str> = Case::Kebab
+            .split(&text)
+            .into_iter()
+            .filter(|word| !word.is_empty())
+            .collect();
+
+        let kept: Vec<&str> = words
+            .iter()
+            .copied()
+            .filter(|word| !self.stop_words.contains(*word))
+            .collect();
+        let words = if kept.is_empty() { words } else { kept };
+        words.into_iter().map(ToString::to_string).collect()
+    }
+}
+
+/// Joins as many of `words` with hyphens as fit in `max_len`, cutting the first word if
+/// it doesn't fit by itself.
+fn join_within(words: &[String], max_len: usize) -> String {
+    let mut slug = String::new();
+    for word in words {
+        let len = if slug.is_empty() {
+            word.len()
+        } else {
+            slug.len() + 1 + word.len()
+        };
+        if len > max_len {
+            break;
+        }
+        if !slug.is_empty() {
+            slug.push('-');
+        }
+        slug.push_str(word);
+    }
+    match words.first() {
+        Some(first) if slug.is_empty() => first[..max_len.min(first.len())].to_string(),
+        _ => slug,
+    }
+}
+
+/// Makes slugs that are unique among every slug it has made or reserved.
+///
+/// When a slug is taken, the lowest number from 2 that makes it unique is appended,
+/// and words are dropped from the end so that the number fits in the maximum length.
+/// When not even a single letter fits before the number, the number alone is the slug.
+/// ```
+/// use convert_case_extras::slug::{SlugRegistry, Slugger};
+///
+/// let mut registry = SlugRegistry::with_slugger(Slugger::new().max_len(12));
+/// registry.reserve("about");
+/// assert_eq!(registry.slug("About"), "about-2");
+/// assert_eq!(registry.slug("About Us"), "about-us");
+/// assert_eq!(registry.slug("About us"), "about-us-2");
+/// assert!(registry.contains("about-us"));
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct SlugRegistry {
+    slugger: Slugger,
+    taken: BTreeSet<String>,
+}
+
+impl SlugRegistry {
+    /// Creates an empty registry that makes slugs with [`Slugger::new`].  This is the
+    /// same as `Default::default()`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an empty registry that makes slugs with `slugger`.
+    pub fn with_slugger(slugger: Slugger) -> Self {
+        SlugRegistry {
+            slugger,
+            taken: BTreeSet::new(),
+        }
+    }
+
+    /// Marks `slug` as taken, like a slug that already exists elsewhere.  Returns false
+    /// if it was already taken.
+    pub fn reserve(&mut self, slug: &str) -> bool {
+        self.taken.insert(slug.to_string())
+    }
+
+    /// Returns true if `slug` has been made or reserved.
+    pub fn contains(&self, slug: &str) -> bool {
+        self.taken.contains(slug)
+    }
+
+    /// Returns a slug of `s` that hasn't been made or reserved before, and marks it as
+    /// taken.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number that makes the slug unique is longer than the maximum length,
+    /// like a tenth "a" with a maximum length of 1.
+    pub fn slug(&mut self, s: &str) -> String {
+        let words = self.slugger.words(s);
+        let max_len = self.slugger.max_len.unwrap_or(usize::MAX);
+
+        let mut slug = join_within(&words, max_len);
+        let mut n = 2;
+        while self.taken.contains(&slug) {
+            let suffix = format!("-{}", n);
+            let base = join_within(&words, max_len.saturating_sub(suffix.len()));
+            slug = if base.is_empty() {
+                n.to_string()
+            } else {
+                base + &suffix
+            };
+            assert!(
+                slug.len() <= max_len,
+                "no unique slug of `{}` fits in {} characters",
+                s,
+                max_len
+            );
+            n += 1;
+        }
+        self.taken.insert(slug.clone());
+        slug
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn punctuation_and_scripts() {
+        assert_eq!(slugify("C++ & Rust: 2024's Guide"), "c-rust-2024s-guide");
+        assert_eq!(
+            slugify("snake_case and camelCase"),
+            "snake-case-and-camelcase"
+        );
+        assert_eq!(slugify("Ærøskøbing — Straße №5"), "aeroskobing-strasse-no5");
+        assert_eq!(slugify("Привет, мир"), "privet-mir");
+        assert_eq!(slugify("🎉 !!! 東京"), "");
+    }
+
+    #[test]
+    fn max_len() {
+        let slugger = Slugger::new().max_len(11);
+        assert_eq!(slugger.slug("hello world"), "hello-world");
+        assert_eq!(slugger.slug("hello worlds"), "hello");
+        assert_eq!(slugger.slug("internationalization"), "internation");
+        assert_eq!(Slugger::new().max_len(1).slug("ab cd"), "a");
+    }
+
+    #[test]
+    #[should_panic]
+    fn max_len_zero() {
+        Slugger::new().max_len(0);
+    }
+
+    #[test]
+    fn stop_words() {
+        let slugger = Slugger::new().stop_words(STOP_WORDS).stop_words(&["Guide"]);
+        assert_eq!(slugger.slug("The GUIDE to the Galaxy"), "galaxy");
+        assert_eq!(slugger.slug("Of The"), "of-the");
+    }
+
+    #[test]
+    fn registry() {
+        let mut registry = SlugRegistry::new();
+        assert_eq!(registry.slug("Part"), "part");
+        assert_eq!(registry.slug("PART"), "part-2");
+        assert_eq!(registry.slug("Part 2"), "part-2-2");
+        assert_eq!(registry.slug("part"), "part-3");
+        assert_eq!(registry.slug(""), "");
+        assert_eq!(registry.slug("!"), "2");
+        assert!(!registry.reserve("part-3"));
+        assert!(registry.reserve("part-4"));
+        assert_eq!(registry.slug("part"), "part-5");
+    }
+
+    #[test]
+    fn registry_max_len() {
+        let mut registry = SlugRegistry::with_slugger(Slugger::new().max_len(10));
+        assert_eq!(registry.slug("big red dog"), "big-red");
+        assert_eq!(registry.slug("big red dog"), "big-red-2");
+        assert_eq!(registry.slug("abcdefghijkl"), "abcdefghij");
+        assert_eq!(registry.slug("abcdefghijkl"), "abcdefgh-2");
+        for n in 3..=9 {
+            assert_eq!(registry.slug("abcdefghijkl"), format!("abcdefgh-{}", n));
+        }
+        assert_eq!(registry.slug("abcdefghijkl"), "abcdefg-10");
+
+        let mut registry = SlugRegistry::with_slugger(Slugger::new().max_len(1));
+        assert_eq!(registry.slug("a"), "a");
+        for n in 2..=9 {
+            assert_eq!(registry.slug("a"), n.to_string());
+        }
+        let mut registry = SlugRegistry::with_slugger(Slugger::new().max_len(3));
+        assert_eq!(registry.slug("abcd"), "abc");
+        assert_eq!(registry.slug("abcd"), "a-2");
+        for _ in 3..=12 {
+            assert!(registry.slug("abcd").len() <= 3);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn registry_max_len_exhausted() {
+        let mut registry = SlugRegistry::with_slugger(Slugger::new().max_len(1));
+        for _ in 1..=10 {
+            registry.slug("a");
+        }
+    }
+}
@@ -0,0 +1,304 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::locale::Locale;
+
+/// Which words a style guide keeps lowercase in a title.
+pub(crate) struct TitleStyle {
+    /// Words that are lowercase unless they begin the title, follow a colon,
+    /// or end the title when `capitalize_last` is set.
+    minor: &'static [&'static str],
+    /// Whether every preposition is minor, regardless of length.
+    all_prepositions: bool,
+    capitalize_last: bool,
+}
+
+const PREPOSITIONS: &[&str] = &[
+    "about",
+    "above",
+    "across",
+    "after",
+    "against",
+    "along",
+    "amid",
+    "among",
+    "around",
+    "as",
+    "at",
+    "before",
+    "behind",
+    "below",
+    "beneath",
+    "beside",
+    "besides",
+    "between",
+    "beyond",
+    "by",
+    "despite",
+    "down",
+    "during",
+    "except",
+    "for",
+    "from",
+    "in",
+    "inside",
+    "into",
+    "like",
+    "near",
+    "of",
+    "off",
+    "on",
+    "onto",
+    "opposite",
+    "out",
+    "outside",
+    "over",
+    "past",
+    "per",
+    "regarding",
+    "round",
+    "since",
+    "than",
+    "through",
+    "throughout",
+    "till",
+    "to",
+    "toward",
+    "towards",
+    "under",
+    "underneath",
+    "unlike",
+    "until",
+    "unto",
+    "up",
+    "upon",
+    "versus",
+    "via",
+    "with",
+    "within",
+    "without",
+];
+
+/// Associated Press: articles, and conjunctions and prepositions of three
+/// letters or fewer.
+pub(crate) const AP: TitleStyle = TitleStyle {
+    minor: &[
+        "a", "an", "the", // articles
+        "and", "but", "for", "nor", "or", "so", "yet", // conjunctions
+        "as", "at", "by", "in", "of", "off", "on", "out", "per", "to", "up", "via",
+    ],
+    all_prepositions: false,
+    capitalize_last: true,
+};
+
+/// Chicago Manual of Style: articles, all prepositions, the coordinating
+/// conjunctions "and", "but", "for", "or" and "nor", and "to" and "as".
+pub(crate) const CHICAGO: TitleStyle = TitleStyle {
+    minor: &[
+        "a", "an", "the", // articles
+        "and", "but", "for", "nor", "or", // conjunctions
+    ],
+    all_prepositions: true,
+    capitalize_last: true,
+};
+
+/// American Psychological Association: articles, and conjunctions and
+/// prepositions of three letters or fewer.  The last word is not treated
+/// specially.
+pub(crate) const APA: TitleStyle = TitleStyle {
+    minor: &[
+        "a", "an", "the", // articles
+        "and", "as", "but", "for", "if", "nor", "or", "so", "yet", // conjunctions
+        "at", "by", "in", "of", "off", "on", "per", "to", "up", "via",
+    ],
+    all_prepositions: false,
+    capitalize_last: false,
+};
+
+/// Modern Language Association: articles, all prepositions, coordinating
+/// conjunctions, and "to" in infinitives.
+pub(crate) const MLA: TitleStyle = TitleStyle {
+    minor: &[
+        "a", "an", "the", // articles
+        "and", "but", "for", "nor", "or", "so", "yet", // conjunctions
+    ],
+    all_prepositions: true,
+    capitalize_last: true,
+};
+
+impl TitleStyle {
+    fn is_minor(&self, word: &str) -> bool {
+        self.minor.contains(&word) || (self.all_prepositions && PREPOSITIONS.contains(&word))
+    }
+
+    /// Capitalizes the words of a title, casing letters following `locale`.
+    pub(crate) fn mutate(&self, words: &[&str], locale: Locale) -> Vec<String> {
+        words
+            .iter()
+            .enumerate()
+            .map(|(i, word)| {
+                let first = i == 0 || words[i - 1].ends_with(':');
+                let last = i + 1 == words.len();
+                self.capitalize(word, first || (last && self.capitalize_last), locale)
+            })
+            .collect()
+    }
+
+    /// Capitalizes each hyphenated part of `word`, except minor parts.  The
+    /// first part is capitalized regardless when `force` is set.  Minor words are
+    /// matched with the default Unicode mappings, since the lists are English.
+    fn capitalize(&self, word: &str, force: bool, locale: Locale) -> String {
+        word.split('-')
+            .enumerate()
+            .map(|(i, part)| {
+                let bare = part
+                    .trim_matches(|c: char| !c.is_alphanumeric())
+                    .to_lowercase();
+                if (force && i == 0) || !self.is_minor(&bare) {
+                    capitalize_first_letter(part, locale)
+                } else {
+                    locale.to_lowercase(part)
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("-")
+    }
+}
+
+/// Uppercases the first letter and lowercases the rest, skipping over leading
+/// punctuation such as quotes.
+fn capitalize_first_letter(word: &str, locale: Locale) -> String {
+    let mut first = true;
+    locale.case_chars(word, |c| {
+        if first && c.is_alphanumeric() {
+            first = false;
+            c.is_alphabetic()
+        } else {
+            false
+        }
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn title(style: &TitleStyle, s: &str) -> String {
+        let words: Vec<&str> = s.split(' ').collect();
+        style.mutate(&words, Locale::Root).join(" ")
+    }
+
+    #[test]
+    fn ap() {
+        let corpus = [
+            ("the lord of the rings", "The Lord of the Rings"),
+            ("a tale of two cities", "A Tale of Two Cities"),
+            ("war and peace", "War and Peace"),
+            (
+                "what we talk about when we talk about love",
+                "What We Talk About When We Talk About Love",
+            ),
+            ("somewhere over the rainbow", "Somewhere Over the Rainbow"),
+            (
+                "star wars: the empire strikes back",
+                "Star Wars: The Empire Strikes Back",
+            ),
+            ("the road to", "The Road To"),
+            ("state-of-the-art design", "State-of-the-Art Design"),
+        ];
+        for (input, expected) in corpus {
+            assert_eq!(title(&AP, input), expected);
+        }
+    }
+
+    #[test]
+    fn chicago() {
+        let corpus = [
+            ("the lord of the rings", "The Lord of the Rings"),
+            (
+                "what we talk about when we talk about love",
+                "What We Talk about When We Talk about Love",
+            ),
+            ("somewhere over the rainbow", "Somewhere over the Rainbow"),
+            ("a walk through the forest", "A Walk through the Forest"),
+            ("so you want to be a writer", "So You Want to Be a Writer"),
+            ("the man who would be king", "The Man Who Would Be King"),
+            (
+                "mastering the art: a guide for beginners",
+                "Mastering the Art: A Guide for Beginners",
+            ),
+            ("what it's about", "What It's About"),
+        ];
+        for (input, expected) in corpus {
+            assert_eq!(title(&CHICAGO, input), expected);
+        }
+    }
+
+    #[test]
+    fn apa() {
+        let corpus = [
+            ("the role of sleep in memory", "The Role of Sleep in Memory"),
+            (
+                "effects of exercise on mood and anxiety",
+                "Effects of Exercise on Mood and Anxiety",
+            ),
+            (
+                "learning with and without feedback",
+                "Learning With and Without Feedback",
+            ),
+            (
+                "a self-report measure of stress",
+                "A Self-Report Measure of Stress",
+            ),
+            (
+                "memory: a review of the literature",
+                "Memory: A Review of the Literature",
+            ),
+            ("what they are thinking of", "What They Are Thinking of"),
+        ];
+        for (input, expected) in corpus {
+            assert_eq!(title(&APA, input), expected);
+        }
+    }
+
+    #[test]
+    fn mla() {
+        let corpus = [
+            ("the sun also rises", "The Sun Also Rises"),
+            ("of mice and men", "Of Mice and Men"),
+            ("a room with a view", "A Room with a View"),
+            ("things fall apart", "Things Fall Apart"),
+            ("between the world and me", "Between the World and Me"),
+            (
+                "the grapes of wrath: a novel",
+                "The Grapes of Wrath: A Novel",
+            ),
+            (
+                "something to look forward to",
+                "Something to Look Forward To",
+            ),
+        ];
+        for (input, expected) in corpus {
+            assert_eq!(title(&MLA, input), expected);
+        }
+    }
+
+    #[test]
+    fn locales() {
+        let words = ["istanbul", "in", "izmir"];
+        assert_eq!(
+            AP.mutate(&words, Locale::Turkish),
+            ["İstanbul", "in", "İzmir"]
+        );
+        assert_eq!(AP.mutate(&["IN", "IRAN"], Locale::Turkish), ["In", "Iran"]);
+    }
+
+    #[test]
+    fn punctuation() {
+        assert_eq!(title(&AP, "\"the end\" of it"), "\"The End\" of It");
+        assert_eq!(
+            title(&CHICAGO, "(in) the nick of time"),
+            "(In) the Nick of Time"
+        );
+    }
+}
@@ -0,0 +1,1207 @@
+//! Transliteration of text into ASCII before changing its case.
+//!
+//! Letters with accents lose them, ligatures and `ß` are expanded, and Greek and
+//! Cyrillic letters are romanized.  Characters without a transliteration, like
+//! combining marks, emoji, or letters of other scripts, are removed, so the result is
+//! always ASCII.  Only available with the "transliterate" feature.
+//!
+//! ```
+//! use convert_case::Case;
+//! use convert_case_extras::transliterate::{transliterate, AsciiCasing};
+//!
+//! assert_eq!(transliterate("Crème Brûlée"), "Creme Brulee");
+//! assert_eq!("Crème Brûlée Straße".to_ascii_case(Case::Snake), "creme_brulee_strasse");
+//! assert_eq!("Щука из Москвы".to_ascii_case(Case::Kebab), "shchuka-iz-moskvy");
+//! ```
+
+use alloc::string::String;
+
+use convert_case::{Case, Casing};
+
+/// Converts `s` into ASCII.
+///
+/// A capital letter that becomes more than one letter, like `Æ` or `Щ`, is
+/// capitalized in mixed case text and uppercased in uppercase text, so that the word
+/// boundaries of the result match those of the original.
+/// ```
+/// use convert_case_extras::transliterate::transliterate;
+///
+/// assert_eq!(transliterate("Ærøskøbing"), "Aeroskobing");
+/// assert_eq!(transliterate("ÆRØSKØBING"), "AEROSKOBING");
+/// assert_eq!(transliterate("Ἀθῆναι"), "Athinai");
+/// assert_eq!(transliterate("ﬁnal café 🎉"), "final cafe ");
+/// ```
+pub fn transliterate(s: &str) -> String {
+    let mut ascii = String::with_capacity(s.len());
+    let mut chars = s.chars().peekable();
+    let mut prev_upper = false;
+    while let Some(c) = chars.next() {
+        if c.is_ascii() {
+            ascii.push(c);
+        } else if let Ok(i) = TABLE.binary_search_by_key(&c, |&(c, _)| c) {
+            let letters = TABLE[i].1;
+            let upper_context = match chars.peek() {
+                Some(next) if next.is_alphabetic() => next.is_uppercase(),
+                _ => prev_upper,
+            };
+            if letters.len() > 1 && c.is_uppercase() && upper_context {
+                ascii.extend(letters.chars().map(|c| c.to_ascii_uppercase()));
+            } else {
+                ascii.push_str(letters);
+            }
+        }
+        if c.is_alphabetic() {
+            prev_upper = c.is_uppercase();
+        }
+    }
+    ascii
+}
+
+/// Converts strings into a case after transliterating them into ASCII.
+pub trait AsciiCasing {
+    /// Transliterates `self` with [`transliterate`], then converts it into `case`.
+    /// ```
+    /// use convert_case::Case;
+    /// use convert_case_extras::transliterate::AsciiCasing;
+    ///
+    /// assert_eq!("Ελληνική Δημοκρατία".to_ascii_case(Case::Pascal), "EllinikiDimokratia");
+    /// assert_eq!("Ærø ŁÓDŹ".to_ascii_case(Case::Constant), "AERO_LODZ");
+    /// ```
+    fn to_ascii_case(&self, case: Case) -> String;
+}
+
+impl<T: AsRef<str> + ?Sized> AsciiCasing for T {
+    fn to_ascii_case(&self, case: Case) -> String {
+        transliterate(self.as_ref()).to_case(case)
+    }
+}
+
+/// Transliterations of characters, sorted by character.  Letters with accents come from
+/// their Unicode compatibility decompositions, and Greek and Cyrillic follow ELOT 743
+/// and the Russian passport romanization.
+#[rustfmt::skip]
+const TABLE: &[(char, &str)] = &[
+    ('\u{a0}', " "),
+    ('¢', "c"),
+    ('£', "GBP"),
+    ('¥', "JPY"),
+    ('©', "(c)"),
+    ('ª', "a"),
+    ('«', "\""),
+    ('®', "(r)"),
+    ('°', "deg"),
+    ('±', "+/-"),
+    ('²', "2"),
+    ('³', "3"),
+    ('µ', "u"),
+    ('·', "."),
+    ('¹', "1"),
+    ('º', "o"),
+    ('»', "\""),
+    ('¼', "1/4"),
+    ('½', "1/2"),
+    ('¾', "3/4"),
+    ('À', "A"),
+    ('Á', "A"),
+    ('Â', "A"),
+    ('Ã', "A"),
+    ('Ä', "A"),
+    ('Å', "A"),
+    ('Æ', "Ae"),
+    ('Ç', "C"),
+    ('È', "E"),
+    ('É', "E"),
+    ('Ê', "E"),
+    ('Ë', "E"),
+    ('Ì', "I"),
+    ('Í', "I"),
+    ('Î', "I"),
+    ('Ï', "I"),
+    ('Ð', "D"),
+    ('Ñ', "N"),
+    ('Ò', "O"),
+    ('Ó', "O"),
+    ('Ô', "O"),
+    ('Õ', "O"),
+    ('Ö', "O"),
+    ('×', "x"),
+    ('Ø', "O"),
+    ('Ù', "U"),
+    ('Ú', "U"),
+    ('Û', "U"),
+    ('Ü', "U"),
+    ('Ý', "Y"),
+    ('Þ', "Th"),
+    ('ß', "ss"),
+    ('à', "a"),
+    ('á', "a"),
+    ('â', "a"),
+    ('ã', "a"),
+    ('ä', "a"),
+    ('å', "a"),
+    ('æ', "ae"),
+    ('ç', "c"),
+    ('è', "e"),
+    ('é', "e"),
+    ('ê', "e"),
+    ('ë', "e"),
+    ('ì', "i"),
+    ('í', "i"),
+    ('î', "i"),
+    ('ï', "i"),
+    ('ð', "d"),
+    ('ñ', "n"),
+    ('ò', "o"),
+    ('ó', "o"),
+    ('ô', "o"),
+    ('õ', "o"),
+    ('ö', "o"),
+    ('÷', "/"),
+    ('ø', "o"),
+    ('ù', "u"),
+    ('ú', "u"),
+    ('û', "u"),
+    ('ü', "u"),
+    ('ý', "y"),
+    ('þ', "th"),
+    ('ÿ', "y"),
+    ('Ā', "A"),
+    ('ā', "a"),
+    ('Ă', "A"),
+    ('ă', "a"),
+    ('Ą', "A"),
+    ('ą', "a"),
+    ('Ć', "C"),
+    ('ć', "c"),
+    ('Ĉ', "C"),
+    ('ĉ', "c"),
+    ('Ċ', "C"),
+    ('ċ', "c"),
+    ('Č', "C"),
+    ('č', "c"),
+    ('Ď', "D"),
+    ('ď', "d"),
+    ('Đ', "D"),
+    ('đ', "d"),
+    ('Ē', "E"),
+    ('ē', "e"),
+    ('Ĕ', "E"),
+    ('ĕ', "e"),
+    ('Ė', "E"),
+    ('ė', "e"),
+    ('Ę', "E"),
+    ('ę', "e"),
+    ('Ě', "E"),
+    ('ě', "e"),
+    ('Ĝ', "G"),
+    ('ĝ', "g"),
+    ('Ğ', "G"),
+    ('ğ', "g"),
+    ('Ġ', "G"),
+    ('ġ', "g"),
+    ('Ģ', "G"),
+    ('ģ', "g"),
+    ('Ĥ', "H"),
+    ('ĥ', "h"),
+    ('Ħ', "H"),
+    ('ħ', "h"),
+    ('Ĩ', "I"),
+    ('ĩ', "i"),
+    ('Ī', "I"),
+    ('ī', "i"),
+    ('Ĭ', "I"),
+    ('ĭ', "i"),
+    ('Į', "I"),
+    ('į', "i"),
+    ('İ', "I"),
+    ('ı', "i"),
+    ('Ĳ', "Ij"),
+    ('ĳ', "ij"),
+    ('Ĵ', "J"),
+    ('ĵ', "j"),
+    ('Ķ', "K"),
+    ('ķ', "k"),
+    ('ĸ', "q"),
+    ('Ĺ', "L"),
+    ('ĺ', "l"),
+    ('Ļ', "L"),
+    ('ļ', "l"),
+    ('Ľ', "L"),
+    ('ľ', "l"),
+    ('Ł', "L"),
+    ('ł', "l"),
+    ('Ń', "N"),
+    ('ń', "n"),
+    ('Ņ', "N"),
+    ('ņ', "n"),
+    ('Ň', "N"),
+    ('ň', "n"),
+    ('ŉ', "'n"),
+    ('Ŋ', "Ng"),
+    ('ŋ', "ng"),
+    ('Ō', "O"),
+    ('ō', "o"),
+    ('Ŏ', "O"),
+    ('ŏ', "o"),
+    ('Ő', "O"),
+    ('ő', "o"),
+    ('Œ', "Oe"),
+    ('œ', "oe"),
+    ('Ŕ', "R"),
+    ('ŕ', "r"),
+    ('Ŗ', "R"),
+    ('ŗ', "r"),
+    ('Ř', "R"),
+    ('ř', "r"),
+    ('Ś', "S"),
+    ('ś', "s"),
+    ('Ŝ', "S"),
+    ('ŝ', "s"),
+    ('Ş', "S"),
+    ('ş', "s"),
+    ('Š', "S"),
+    ('š', "s"),
+    ('Ţ', "T"),
+    ('ţ', "t"),
+    ('Ť', "T"),
+    ('ť', "t"),
+    ('Ŧ', "T"),
+    ('ŧ', "t"),
+    ('Ũ', "U"),
+    ('ũ', "u"),
+    ('Ū', "U"),
+    ('ū', "u"),
+    ('Ŭ', "U"),
+    ('ŭ', "u"),
+    ('Ů', "U"),
+    ('ů', "u"),
+    ('Ű', "U"),
+    ('ű', "u"),
+    ('Ų', "U"),
+    ('ų', "u"),
+    ('Ŵ', "W"),
+    ('ŵ', "w"),
+    ('Ŷ', "Y"),
+    ('ŷ', "y"),
+    ('Ÿ', "Y"),
+    ('Ź', "Z"),
+    ('ź', "z"),
+    ('Ż', "Z"),
+    ('ż', "z"),
+    ('Ž', "Z"),
+    ('ž', "z"),
+    ('ſ', "s"),
+    ('ƀ', "b"),
+    ('Ɓ', "B"),
+    ('Ƈ', "C"),
+    ('ƈ', "c"),
+    ('Ɗ', "D"),
+    ('Ǝ', "E"),
+    ('Ə', "E"),
+    ('ƒ', "f"),
+    ('Ɣ', "G"),
+    ('Ɨ', "I"),
+    ('Ƙ', "K"),
+    ('ƙ', "k"),
+    ('ƚ', "l"),
+    ('Ơ', "O"),
+    ('ơ', "o"),
+    ('Ƥ', "P"),
+    ('ƥ', "p"),
+    ('Ƭ', "T"),
+    ('ƭ', "t"),
+    ('Ư', "U"),
+    ('ư', "u"),
+    ('Ƴ', "Y"),
+    ('ƴ', "y"),
+    ('Ƶ', "Z"),
+    ('ƶ', "z"),
+    ('Ʒ', "Zh"),
+    ('Ǆ', "DZ"),
+    ('ǅ', "Dz"),
+    ('ǆ', "dz"),
+    ('Ǉ', "LJ"),
+    ('ǈ', "Lj"),
+    ('ǉ', "lj"),
+    ('Ǌ', "NJ"),
+    ('ǋ', "Nj"),
+    ('ǌ', "nj"),
+    ('Ǎ', "A"),
+    ('ǎ', "a"),
+    ('Ǐ', "I"),
+    ('ǐ', "i"),
+    ('Ǒ', "O"),
+    ('ǒ', "o"),
+    ('Ǔ', "U"),
+    ('ǔ', "u"),
+    ('Ǖ', "U"),
+    ('ǖ', "u"),
+    ('Ǘ', "U"),
+    ('ǘ', "u"),
+    ('Ǚ', "U"),
+    ('ǚ', "u"),
+    ('Ǜ', "U"),
+    ('ǜ', "u"),
+    ('ǝ', "e"),
+    ('Ǟ', "A"),
+    ('ǟ', "a"),
+    ('Ǡ', "A"),
+    ('ǡ', "a"),
+    ('Ǣ', "Ae"),
+    ('ǣ', "ae"),
+    ('Ǧ', "G"),
+    ('ǧ', "g"),
+    ('Ǩ', "K"),
+    ('ǩ', "k"),
+    ('Ǫ', "O"),
+    ('ǫ', "o"),
+    ('Ǭ', "O"),
+    ('ǭ', "o"),
+    ('Ǯ', "Zh"),
+    ('ǯ', "zh"),
+    ('ǰ', "j"),
+    ('Ǳ', "DZ"),
+    ('ǲ', "Dz"),
+    ('ǳ', "dz"),
+    ('Ǵ', "G"),
+    ('ǵ', "g"),
+    ('Ǹ', "N"),
+    ('ǹ', "n"),
+    ('Ǻ', "A"),
+    ('ǻ', "a"),
+    ('Ǽ', "Ae"),
+    ('ǽ', "ae"),
+    ('Ǿ', "O"),
+    ('ǿ', "o"),
+    ('Ȁ', "A"),
+    ('ȁ', "a"),
+    ('Ȃ', "A"),
+    ('ȃ', "a"),
+    ('Ȅ', "E"),
+    ('ȅ', "e"),
+    ('Ȇ', "E"),
+    ('ȇ', "e"),
+    ('Ȉ', "I"),
+    ('ȉ', "i"),
+    ('Ȋ', "I"),
+    ('ȋ', "i"),
+    ('Ȍ', "O"),
+    ('ȍ', "o"),
+    ('Ȏ', "O"),
+    ('ȏ', "o"),
+    ('Ȑ', "R"),
+    ('ȑ', "r"),
+    ('Ȓ', "R"),
+    ('ȓ', "r"),
+    ('Ȕ', "U"),
+    ('ȕ', "u"),
+    ('Ȗ', "U"),
+    ('ȗ', "u"),
+    ('Ș', "S"),
+    ('ș', "s"),
+    ('Ț', "T"),
+    ('ț', "t"),
+    ('Ȝ', "Y"),
+    ('ȝ', "y"),
+    ('Ȟ', "H"),
+    ('ȟ', "h"),
+    ('Ȧ', "A"),
+    ('ȧ', "a"),
+    ('Ȩ', "E"),
+    ('ȩ', "e"),
+    ('Ȫ', "O"),
+    ('ȫ', "o"),
+    ('Ȭ', "O"),
+    ('ȭ', "o"),
+    ('Ȯ', "O"),
+    ('ȯ', "o"),
+    ('Ȱ', "O"),
+    ('ȱ', "o"),
+    ('Ȳ', "Y"),
+    ('ȳ', "y"),
+    ('ȸ', "db"),
+    ('ȹ', "qp"),
+    ('Ⱥ', "A"),
+    ('Ȼ', "C"),
+    ('ȼ', "c"),
+    ('Ƚ', "L"),
+    ('ȿ', "s"),
+    ('ɀ', "z"),
+    ('Ƀ', "B"),
+    ('Ɇ', "E"),
+    ('ɇ', "e"),
+    ('Ɉ', "J"),
+    ('ɉ', "j"),
+    ('Ɍ', "R"),
+    ('ɍ', "r"),
+    ('Ɏ', "Y"),
+    ('ɏ', "y"),
+    (';', ";"),
+    ('Ά', "A"),
+    ('·', "."),
+    ('Έ', "E"),
+    ('Ή', "I"),
+    ('Ί', "I"),
+    ('Ό', "O"),
+    ('Ύ', "Y"),
+    ('Ώ', "O"),
+    ('ΐ', "i"),
+    ('Α', "A"),
+    ('Β', "V"),
+    ('Γ', "G"),
+    ('Δ', "D"),
+    ('Ε', "E"),
+    ('Ζ', "Z"),
+    ('Η', "I"),
+    ('Θ', "Th"),
+    ('Ι', "I"),
+    ('Κ', "K"),
+    ('Λ', "L"),
+    ('Μ', "M"),
+    ('Ν', "N"),
+    ('Ξ', "X"),
+    ('Ο', "O"),
+    ('Π', "P"),
+    ('Ρ', "R"),
+    ('Σ', "S"),
+    ('Τ', "T"),
+    ('Υ', "Y"),
+    ('Φ', "F"),
+    ('Χ', "Ch"),
+    ('Ψ', "Ps"),
+    ('Ω', "O"),
+    ('Ϊ', "I"),
+    ('Ϋ', "Y"),
+    ('ά', "a"),
+    ('έ', "e"),
+    ('ή', "i"),
+    ('ί', "i"),
+    ('ΰ', "y"),
+    ('α', "a"),
+    ('β', "v"),
+    ('γ', "g"),
+    ('δ', "d"),
+    ('ε', "e"),
+    ('ζ', "z"),
+    ('η', "i"),
+    ('θ', "th"),
+    ('ι', "i"),
+    ('κ', "k"),
+    ('λ', "l"),
+    ('μ', "m"),
+    ('ν', "n"),
+    ('ξ', "x"),
+    ('ο', "o"),
+    ('π', "p"),
+    ('ρ', "r"),
+    ('ς', "s"),
+    ('σ', "s"),
+    ('τ', "t"),
+    ('υ', "y"),
+    ('φ', "f"),
+    ('χ', "ch"),
+    ('ψ', "ps"),
+    ('ω', "o"),
+    ('ϊ', "i"),
+    ('ϋ', "y"),
+    ('ό', "o"),
+    ('ύ', "y"),
+    ('ώ', "o"),
+    ('ϐ', "v"),
+    ('ϑ', "th"),
+    ('ϒ', "Y"),
+    ('ϓ', "Y"),
+    ('ϔ', "Y"),
+    ('ϕ', "f"),
+    ('ϖ', "p"),
+    ('ϰ', "k"),
+    ('ϱ', "r"),
+    ('ϲ', "s"),
+    ('ϴ', "Th"),
+    ('ϵ', "e"),
+    ('Ϲ', "S"),
+    ('Ѐ', "E"),
+    ('Ё', "Yo"),
+    ('Ђ', "Dj"),
+    ('Ѓ', "G"),
+    ('Є', "Ye"),
+    ('Ѕ', "Dz"),
+    ('І', "I"),
+    ('Ї', "Yi"),
+    ('Ј', "J"),
+    ('Љ', "Lj"),
+    ('Њ', "Nj"),
+    ('Ћ', "C"),
+    ('Ќ', "K"),
+    ('Ѝ', "I"),
+    ('Ў', "U"),
+    ('Џ', "Dz"),
+    ('А', "A"),
+    ('Б', "B"),
+    ('В', "V"),
+    ('Г', "G"),
+    ('Д', "D"),
+    ('Е', "E"),
+    ('Ж', "Zh"),
+    ('З', "Z"),
+    ('И', "I"),
+    ('Й', "Y"),
+    ('К', "K"),
+    ('Л', "L"),
+    ('М', "M"),
+    ('Н', "N"),
+    ('О', "O"),
+    ('П', "P"),
+    ('Р', "R"),
+    ('С', "S"),
+    ('Т', "T"),
+    ('У', "U"),
+    ('Ф', "F"),
+    ('Х', "Kh"),
+    ('Ц', "Ts"),
+    ('Ч', "Ch"),
+    ('Ш', "Sh"),
+    ('Щ', "Shch"),
+    ('Ы', "Y"),
+    ('Э', "E"),
+    ('Ю', "Yu"),
+    ('Я', "Ya"),
+    ('а', "a"),
+    ('б', "b"),
+    ('в', "v"),
+    ('г', "g"),
+    ('д', "d"),
+    ('е', "e"),
+    ('ж', "zh"),
+    ('з', "z"),
+    ('и', "i"),
+    ('й', "y"),
+    ('к', "k"),
+    ('л', "l"),
+    ('м', "m"),
+    ('н', "n"),
+    ('о', "o"),
+    ('п', "p"),
+    ('р', "r"),
+    ('с', "s"),
+    ('т', "t"),
+    ('у', "u"),
+    ('ф', "f"),
+    ('х', "kh"),
+    ('ц', "ts"),
+    ('ч', "ch"),
+    ('ш', "sh"),
+    ('щ', "shch"),
+    ('ы', "y"),
+    ('э', "e"),
+    ('ю', "yu"),
+    ('я', "ya"),
+    ('ѐ', "e"),
+    ('ё', "yo"),
+    ('ђ', "dj"),
+    ('ѓ', "g"),
+    ('є', "ye"),
+    ('ѕ', "dz"),
+    ('і', "i"),
+    ('ї', "yi"),
+    ('ј', "j"),
+    ('љ', "lj"),
+    ('њ', "nj"),
+    ('ћ', "c"),
+    ('ќ', "k"),
+    ('ѝ', "i"),
+    ('ў', "u"),
+    ('џ', "dz"),
+    ('Ḁ', "A"),
+    ('ḁ', "a"),
+    ('Ḃ', "B"),
+    ('ḃ', "b"),
+    ('Ḅ', "B"),
+    ('ḅ', "b"),
+    ('Ḇ', "B"),
+    ('ḇ', "b"),
+    ('Ḉ', "C"),
+    ('ḉ', "c"),
+    ('Ḋ', "D"),
+    ('ḋ', "d"),
+    ('Ḍ', "D"),
+    ('ḍ', "d"),
+    ('Ḏ', "D"),
+    ('ḏ', "d"),
+    ('Ḑ', "D"),
+    ('ḑ', "d"),
+    ('Ḓ', "D"),
+    ('ḓ', "d"),
+    ('Ḕ', "E"),
+    ('ḕ', "e"),
+    ('Ḗ', "E"),
+    ('ḗ', "e"),
+    ('Ḙ', "E"),
+    ('ḙ', "e"),
+    ('Ḛ', "E"),
+    ('ḛ', "e"),
+    ('Ḝ', "E"),
+    ('ḝ', "e"),
+    ('Ḟ', "F"),
+    ('ḟ', "f"),
+    ('Ḡ', "G"),
+    ('ḡ', "g"),
+    ('Ḣ', "H"),
+    ('ḣ', "h"),
+    ('Ḥ', "H"),
+    ('ḥ', "h"),
+    ('Ḧ', "H"),
+    ('ḧ', "h"),
+    ('Ḩ', "H"),
+    ('ḩ', "h"),
+    ('Ḫ', "H"),
+    ('ḫ', "h"),
+    ('Ḭ', "I"),
+    ('ḭ', "i"),
+    ('Ḯ', "I"),
+    ('ḯ', "i"),
+    ('Ḱ', "K"),
+    ('ḱ', "k"),
+    ('Ḳ', "K"),
+    ('ḳ', "k"),
+    ('Ḵ', "K"),
+    ('ḵ', "k"),
+    ('Ḷ', "L"),
+    ('ḷ', "l"),
+    ('Ḹ', "L"),
+    ('ḹ', "l"),
+    ('Ḻ', "L"),
+    ('ḻ', "l"),
+    ('Ḽ', "L"),
+    ('ḽ', "l"),
+    ('Ḿ', "M"),
+    ('ḿ', "m"),
+    ('Ṁ', "M"),
+    ('ṁ', "m"),
+    ('Ṃ', "M"),
+    ('ṃ', "m"),
+    ('Ṅ', "N"),
+    ('ṅ', "n"),
+    ('Ṇ', "N"),
+    ('ṇ', "n"),
+    ('Ṉ', "N"),
+    ('ṉ', "n"),
+    ('Ṋ', "N"),
+    ('ṋ', "n"),
+    ('Ṍ', "O"),
+    ('ṍ', "o"),
+    ('Ṏ', "O"),
+    ('ṏ', "o"),
+    ('Ṑ', "O"),
+    ('ṑ', "o"),
+    ('Ṓ', "O"),
+    ('ṓ', "o"),
+    ('Ṕ', "P"),
+    ('ṕ', "p"),
+    ('Ṗ', "P"),
+    ('ṗ', "p"),
+    ('Ṙ', "R"),
+    ('ṙ', "r"),
+    ('Ṛ', "R"),
+    ('ṛ', "r"),
+    ('Ṝ', "R"),
+    ('ṝ', "r"),
+    ('Ṟ', "R"),
+    ('ṟ', "r"),
+    ('Ṡ', "S"),
+    ('ṡ', "s"),
+    ('Ṣ', "S"),
+    ('ṣ', "s"),
+    ('Ṥ', "S"),
+    ('ṥ', "s"),
+    ('Ṧ', "S"),
+    ('ṧ', "s"),
+    ('Ṩ', "S"),
+    ('ṩ', "s"),
+    ('Ṫ', "T"),
+    ('ṫ', "t"),
+    ('Ṭ', "T"),
+    ('ṭ', "t"),
+    ('Ṯ', "T"),
+    ('ṯ', "t"),
+    ('Ṱ', "T"),
+    ('ṱ', "t"),
+    ('Ṳ', "U"),
+    ('ṳ', "u"),
+    ('Ṵ', "U"),
+    ('ṵ', "u"),
+    ('Ṷ', "U"),
+    ('ṷ', "u"),
+    ('Ṹ', "U"),
+    ('ṹ', "u"),
+    ('Ṻ', "U"),
+    ('ṻ', "u"),
+    ('Ṽ', "V"),
+    ('ṽ', "v"),
+    ('Ṿ', "V"),
+    ('ṿ', "v"),
+    ('Ẁ', "W"),
+    ('ẁ', "w"),
+    ('Ẃ', "W"),
+    ('ẃ', "w"),
+    ('Ẅ', "W"),
+    ('ẅ', "w"),
+    ('Ẇ', "W"),
+    ('ẇ', "w"),
+    ('Ẉ', "W"),
+    ('ẉ', "w"),
+    ('Ẋ', "X"),
+    ('ẋ', "x"),
+    ('Ẍ', "X"),
+    ('ẍ', "x"),
+    ('Ẏ', "Y"),
+    ('ẏ', "y"),
+    ('Ẑ', "Z"),
+    ('ẑ', "z"),
+    ('Ẓ', "Z"),
+    ('ẓ', "z"),
+    ('Ẕ', "Z"),
+    ('ẕ', "z"),
+    ('ẖ', "h"),
+    ('ẗ', "t"),
+    ('ẘ', "w"),
+    ('ẙ', "y"),
+    ('ẛ', "s"),
+    ('ẞ', "Ss"),
+    ('Ạ', "A"),
+    ('ạ', "a"),
+    ('Ả', "A"),
+    ('ả', "a"),
+    ('Ấ', "A"),
+    ('ấ', "a"),
+    ('Ầ', "A"),
+    ('ầ', "a"),
+    ('Ẩ', "A"),
+    ('ẩ', "a"),
+    ('Ẫ', "A"),
+    ('ẫ', "a"),
+    ('Ậ', "A"),
+    ('ậ', "a"),
+    ('Ắ', "A"),
+    ('ắ', "a"),
+    ('Ằ', "A"),
+    ('ằ', "a"),
+    ('Ẳ', "A"),
+    ('ẳ', "a"),
+    ('Ẵ', "A"),
+    ('ẵ', "a"),
+    ('Ặ', "A"),
+    ('ặ', "a"),
+    ('Ẹ', "E"),
+    ('ẹ', "e"),
+    ('Ẻ', "E"),
+    ('ẻ', "e"),
+    ('Ẽ', "E"),
+    ('ẽ', "e"),
+    ('Ế', "E"),
+    ('ế', "e"),
+    ('Ề', "E"),
+    ('ề', "e"),
+    ('Ể', "E"),
+    ('ể', "e"),
+    ('Ễ', "E"),
+    ('ễ', "e"),
+    ('Ệ', "E"),
+    ('ệ', "e"),
+    ('Ỉ', "I"),
+    ('ỉ', "i"),
+    ('Ị', "I"),
+    ('ị', "i"),
+    ('Ọ', "O"),
+    ('ọ', "o"),
+    ('Ỏ', "O"),
+    ('ỏ', "o"),
+    ('Ố', "O"),
+    ('ố', "o"),
+    ('Ồ', "O"),
+    ('ồ', "o"),
+    ('Ổ', "O"),
+    ('ổ', "o"),
+    ('Ỗ', "O"),
+    ('ỗ', "o"),
+    ('Ộ', "O"),
+    ('ộ', "o"),
+    ('Ớ', "O"),
+    ('ớ', "o"),
+    ('Ờ', "O"),
+    ('ờ', "o"),
+    ('Ở', "O"),
+    ('ở', "o"),
+    ('Ỡ', "O"),
+    ('ỡ', "o"),
+    ('Ợ', "O"),
+    ('ợ', "o"),
+    ('Ụ', "U"),
+    ('ụ', "u"),
+    ('Ủ', "U"),
+    ('ủ', "u"),
+    ('Ứ', "U"),
+    ('ứ', "u"),
+    ('Ừ', "U"),
+    ('ừ', "u"),
+    ('Ử', "U"),
+    ('ử', "u"),
+    ('Ữ', "U"),
+    ('ữ', "u"),
+    ('Ự', "U"),
+    ('ự', "u"),
+    ('Ỳ', "Y"),
+    ('ỳ', "y"),
+    ('Ỵ', "Y"),
+    ('ỵ', "y"),
+    ('Ỷ', "Y"),
+    ('ỷ', "y"),
+    ('Ỹ', "Y"),
+    ('ỹ', "y"),
+    ('ἀ', "a"),
+    ('ἁ', "a"),
+    ('ἂ', "a"),
+    ('ἃ', "a"),
+    ('ἄ', "a"),
+    ('ἅ', "a"),
+    ('ἆ', "a"),
+    ('ἇ', "a"),
+    ('Ἀ', "A"),
+    ('Ἁ', "A"),
+    ('Ἂ', "A"),
+    ('Ἃ', "A"),
+    ('Ἄ', "A"),
+    ('Ἅ', "A"),
+    ('Ἆ', "A"),
+    ('Ἇ', "A"),
+    ('ἐ', "e"),
+    ('ἑ', "e"),
+    ('ἒ', "e"),
+    ('ἓ', "e"),
+    ('ἔ', "e"),
+    ('ἕ', "e"),
+    ('Ἐ', "E"),
+    ('Ἑ', "E"),
+    ('Ἒ', "E"),
+    ('Ἓ', "E"),
+    ('Ἔ', "E"),
+    ('Ἕ', "E"),
+    ('ἠ', "i"),
+    ('ἡ', "i"),
+    ('ἢ', "i"),
+    ('ἣ', "i"),
+    ('ἤ', "i"),
+    ('ἥ', "i"),
+    ('ἦ', "i"),
+    ('ἧ', "i"),
+    ('Ἠ', "I"),
+    ('Ἡ', "I"),
+    ('Ἢ', "I"),
+    ('Ἣ', "I"),
+    ('Ἤ', "I"),
+    ('Ἥ', "I"),
+    ('Ἦ', "I"),
+    ('Ἧ', "I"),
+    ('ἰ', "i"),
+    ('ἱ', "i"),
+    ('ἲ', "i"),
+    ('ἳ', "i"),
+    ('ἴ', "i"),
+    ('ἵ', "i"),
+    ('ἶ', "i"),
+    ('ἷ', "i"),
+    ('Ἰ', "I"),
+    ('Ἱ', "I"),
+    ('Ἲ', "I"),
+    ('Ἳ', "I"),
+    ('Ἴ', "I"),
+    ('Ἵ', "I"),
+    ('Ἶ', "I"),
+    ('Ἷ', "I"),
+    ('ὀ', "o"),
+    ('ὁ', "o"),
+    ('ὂ', "o"),
+    ('ὃ', "o"),
+    ('ὄ', "o"),
+    ('ὅ', "o"),
+    ('Ὀ', "O"),
+    ('Ὁ', "O"),
+    ('Ὂ', "O"),
+    ('Ὃ', "O"),
+    ('Ὄ', "O"),
+    ('Ὅ', "O"),
+    ('ὐ', "y"),
+    ('ὑ', "y"),
+    ('ὒ', "y"),
+    ('ὓ', "y"),
+    ('ὔ', "y"),
+    ('ὕ', "y"),
+    ('ὖ', "y"),
+    ('ὗ', "y"),
+    ('Ὑ', "Y"),
+    ('Ὓ', "Y"),
+    ('Ὕ', "Y"),
+    ('Ὗ', "Y"),
+    ('ὠ', "o"),
+    ('ὡ', "o"),
+    ('ὢ', "o"),
+    ('ὣ', "o"),
+    ('ὤ', "o"),
+    ('ὥ', "o"),
+    ('ὦ', "o"),
+    ('ὧ', "o"),
+    ('Ὠ', "O"),
+    ('Ὡ', "O"),
+    ('Ὢ', "O"),
+    ('Ὣ', "O"),
+    ('Ὤ', "O"),
+    ('Ὥ', "O"),
+    ('Ὦ', "O"),
+    ('Ὧ', "O"),
+    ('ὰ', "a"),
+    ('ά', "a"),
+    ('ὲ', "e"),
+    ('έ', "e"),
+    ('ὴ', "i"),
+    ('ή', "i"),
+    ('ὶ', "i"),
+    ('ί', "i"),
+    ('ὸ', "o"),
+    ('ό', "o"),
+    ('ὺ', "y"),
+    ('ύ', "y"),
+    ('ὼ', "o"),
+    ('ώ', "o"),
+    ('ᾀ', "a"),
+    ('ᾁ', "a"),
+    ('ᾂ', "a"),
+    ('ᾃ', "a"),
+    ('ᾄ', "a"),
+    ('ᾅ', "a"),
+    ('ᾆ', "a"),
+    ('ᾇ', "a"),
+    ('ᾈ', "A"),
+    ('ᾉ', "A"),
+    ('ᾊ', "A"),
+    ('ᾋ', "A"),
+    ('ᾌ', "A"),
+    ('ᾍ', "A"),
+    ('ᾎ', "A"),
+    ('ᾏ', "A"),
+    ('ᾐ', "i"),
+    ('ᾑ', "i"),
+    ('ᾒ', "i"),
+    ('ᾓ', "i"),
+    ('ᾔ', "i"),
+    ('ᾕ', "i"),
+    ('ᾖ', "i"),
+    ('ᾗ', "i"),
+    ('ᾘ', "I"),
+    ('ᾙ', "I"),
+    ('ᾚ', "I"),
+    ('ᾛ', "I"),
+    ('ᾜ', "I"),
+    ('ᾝ', "I"),
+    ('ᾞ', "I"),
+    ('ᾟ', "I"),
+    ('ᾠ', "o"),
+    ('ᾡ', "o"),
+    ('ᾢ', "o"),
+    ('ᾣ', "o"),
+    ('ᾤ', "o"),
+    ('ᾥ', "o"),
+    ('ᾦ', "o"),
+    ('ᾧ', "o"),
+    ('ᾨ', "O"),
+    ('ᾩ', "O"),
+    ('ᾪ', "O"),
+    ('ᾫ', "O"),
+    ('ᾬ', "O"),
+    ('ᾭ', "O"),
+    ('ᾮ', "O"),
+    ('ᾯ', "O"),
+    ('ᾰ', "a"),
+    ('ᾱ', "a"),
+    ('ᾲ', "a"),
+    ('ᾳ', "a"),
+    ('ᾴ', "a"),
+    ('ᾶ', "a"),
+    ('ᾷ', "a"),
+    ('Ᾰ', "A"),
+    ('Ᾱ', "A"),
+    ('Ὰ', "A"),
+    ('Ά', "A"),
+    ('ᾼ', "A"),
+    ('ι', "i"),
+    ('ῂ', "i"),
+    ('ῃ', "i"),
+    ('ῄ', "i"),
+    ('ῆ', "i"),
+    ('ῇ', "i"),
+    ('Ὲ', "E"),
+    ('Έ', "E"),
+    ('Ὴ', "I"),
+    ('Ή', "I"),
+    ('ῌ', "I"),
+    ('ῐ', "i"),
+    ('ῑ', "i"),
+    ('ῒ', "i"),
+    ('ΐ', "i"),
+    ('ῖ', "i"),
+    ('ῗ', "i"),
+    ('Ῐ', "I"),
+    ('Ῑ', "I"),
+    ('Ὶ', "I"),
+    ('Ί', "I"),
+    ('ῠ', "y"),
+    ('ῡ', "y"),
+    ('ῢ', "y"),
+    ('ΰ', "y"),
+    ('ῤ', "r"),
+    ('ῥ', "r"),
+    ('ῦ', "y"),
+    ('ῧ', "y"),
+    ('Ῠ', "Y"),
+    ('Ῡ', "Y"),
+    ('Ὺ', "Y"),
+    ('Ύ', "Y"),
+    ('Ῥ', "R"),
+    ('`', "`"),
+    ('ῲ', "o"),
+    ('ῳ', "o"),
+    ('ῴ', "o"),
+    ('ῶ', "o"),
+    ('ῷ', "o"),
+    ('Ὸ', "O"),
+    ('Ό', "O"),
+    ('Ὼ', "O"),
+    ('Ώ', "O"),
+    ('ῼ', "O"),
+    ('\u{2000}', " "),
+    ('\u{2001}', " "),
+    ('\u{2002}', " "),
+    ('\u{2003}', " "),
+    ('\u{2004}', " "),
+    ('\u{2005}', " "),
+    ('\u{2006}', " "),
+    ('\u{2007}', " "),
+    ('\u{2008}', " "),
+    ('\u{2009}', " "),
+    ('\u{200a}', " "),
+    ('‐', "-"),
+    ('‑', "-"),
+    ('‒', "-"),
+    ('–', "-"),
+    ('—', "-"),
+    ('―', "-"),
+    ('‘', "'"),
+    ('’', "'"),
+    ('‚', "'"),
+    ('‛', "'"),
+    ('“', "\""),
+    ('”', "\""),
+    ('„', "\""),
+    ('‟', "\""),
+    ('•', "*"),
+    ('․', "."),
+    ('‥', ".."),
+    ('…', "..."),
+    ('\u{202f}', " "),
+    ('′', "'"),
+    ('″', "\""),
+    ('‹', "'"),
+    ('›', "'"),
+    ('‼', "!!"),
+    ('⁇', "??"),
+    ('⁈', "?!"),
+    ('⁉', "!?"),
+    ('\u{205f}', " "),
+    ('€', "EUR"),
+    ('℀', "a/c"),
+    ('℁', "a/s"),
+    ('ℂ', "C"),
+    ('℅', "c/o"),
+    ('℆', "c/u"),
+    ('ℊ', "g"),
+    ('ℋ', "H"),
+    ('ℌ', "H"),
+    ('ℍ', "H"),
+    ('ℎ', "h"),
+    ('ℏ', "h"),
+    ('ℐ', "I"),
+    ('ℑ', "I"),
+    ('ℒ', "L"),
+    ('ℓ', "l"),
+    ('ℕ', "N"),
+    ('№', "No"),
+    ('ℙ', "P"),
+    ('ℚ', "Q"),
+    ('ℛ', "R"),
+    ('ℜ', "R"),
+    ('ℝ', "R"),
+    ('℠', "SM"),
+    ('℡', "TEL"),
+    ('™', "TM"),
+    ('ﬀ', "ff"),
+    ('ﬁ', "fi"),
+    ('ﬂ', "fl"),
+    ('ﬃ', "ffi"),
+    ('ﬄ', "ffl"),
+    ('ﬅ', "st"),
+    ('ﬆ', "st"),
+];
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn table_is_sorted() {
+        assert!(TABLE.windows(2).all(|w| w[0].0 < w[1].0));
+        assert!(TABLE.iter().all(|(c, s)| !c.is_ascii() && s.is_ascii()));
+    }
+
+    #[test]
+    fn latin() {
+        assert_eq!(
+            transliterate("Ça déjà vu, naïve Zoë"),
+            "Ca deja vu, naive Zoe"
+        );
+        assert_eq!(
+            transliterate("Łódź Ørsted Þór Œuvre"),
+            "Lodz Orsted Thor Oeuvre"
+        );
+        assert_eq!(transliterate("Tiếng Việt"), "Tieng Viet");
+        assert_eq!(transliterate("STRAẞE straße"), "STRASSE strasse");
+        assert_eq!(transliterate("ﬂoor ǅemal Ĳssel"), "floor Dzemal Ijssel");
+    }
+
+    #[test]
+    fn decomposed() {
+        assert_eq!(
+            transliterate("Cre\u{300}me bru\u{302}le\u{301}e"),
+            "Creme brulee"
+        );
+        assert_eq!(transliterate("a\u{200d}b\u{a0}c"), "ab c");
+    }
+
+    #[test]
+    fn greek_and_cyrillic() {
+        assert_eq!(transliterate("Θεσσαλονίκη"), "Thessaloniki");
+        assert_eq!(transliterate("ΨΥΧΗ ψυχή"), "PSYCHI psychi");
+        assert_eq!(transliterate("Съешь же ещё"), "Sesh zhe eshchyo");
+        assert_eq!(transliterate("ЩИ"), "SHCHI");
+        assert_eq!(transliterate("Україна"), "Ukrayina");
+    }
+
+    #[test]
+    fn ascii_cases() {
+        let input = "Ünïcödé Wörd Ωmega";
+        for case in Case::all_cases() {
+            assert!(input.to_ascii_case(*case).is_ascii(), "{:?}", case);
+        }
+        assert_eq!("ÜberÆon".to_ascii_case(Case::Snake), "uber_aeon");
+        assert_eq!("東京Tower".to_ascii_case(Case::Snake), "tower");
+        assert_eq!("ǄEMAL_ŠKRLJ".to_ascii_case(Case::Kebab), "dzemal-skrlj");
+    }
+}
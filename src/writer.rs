@@ -0,0 +1,556 @@
+use alloc::vec::Vec;
+use core::fmt::{self, Write};
+
+use convert_case::{Boundary, Case, Pattern};
+use unicode_segmentation::{GraphemeIndices, UnicodeSegmentation};
+
+use crate::locale;
+
+/// The number of graphemes the boundaries in `convert_case` are given to look at.
+/// They look at no more than 3.
+const LOOKAHEAD: usize = 16;
+
+const DEFAULT_BOUNDARIES: [Boundary; 9] = Boundary::defaults();
+
+/// The boundaries used by `Casing::to_case`, as a case.
+const DEFAULT_FROM: Case<'static> = Case::Custom {
+    boundaries: &DEFAULT_BOUNDARIES,
+    pattern: Pattern::Noop,
+    delim: "",
+};
+
+/// Converts `s` into `case` and writes it to `out`.
+///
+/// This writes the same string as [`to_case`](convert_case::Casing::to_case), but
+/// splits, mutates and joins the words in a single pass without allocating.  The
+/// patterns from `convert_case` are written a character at a time.  Cases with a
+/// [`Pattern::Custom`], like the ones in [`case`](crate::case), and the random
+/// cases still allocate the words they mutate.  Splitting on a [`Boundary::Custom`],
+/// like the ones from `Boundary::from_delim`, allocates a list of the graphemes of
+/// `s`, since its condition is given the rest of the string.
+///
+/// Only available with the "unicode-segmentation" feature.
+/// ```
+/// use convert_case::{Case, Casing};
+/// use convert_case_extras::convert_into;
+///
+/// let mut line = String::from("user: ");
+/// convert_into("userAccountID", Case::Snake, &mut line).unwrap();
+/// assert_eq!(line, "user: user_account_id");
+/// assert_eq!(line[6..], "userAccountID".to_case(Case::Snake));
+/// ```
+pub fn convert_into<W>(s: &str, case: Case, out: &mut W) -> fmt::Result
+where
+    W: Write + ?Sized,
+{
+    write_case(s, &DEFAULT_BOUNDARIES, case.pattern(), case.delim(), out)
+}
+
+/// Writes strings converted into a case to an inner writer, without allocating.
+///
+/// Every string is converted as with [`convert_into`], and is split using
+/// `Boundary::defaults()` unless other boundaries are given.  Strings are written
+/// back to back, so any separator between them is written to the inner writer
+/// directly.  Only available with the "unicode-segmentation" feature.
+/// ```
+/// use core::fmt::Write;
+/// use convert_case::Case;
+/// use convert_case_extras::CaseWriter;
+///
+/// let mut writer = CaseWriter::new(String::new(), Case::Constant).from_case(Case::Kebab);
+/// for key in ["log-level", "max-connections"] {
+///     writer.convert(key).unwrap();
+///     writer.get_mut().write_char('\n').unwrap();
+/// }
+/// assert_eq!(writer.into_inner(), "LOG_LEVEL\nMAX_CONNECTIONS\n");
+/// ```
+///
+/// With the "std" feature, the inner writer can also be an [`io::Write`](std::io::Write)
+/// using [`convert_io`](CaseWriter::convert_io).
+#[derive(Debug, Clone)]
+pub struct CaseWriter<'b, W> {
+    inner: W,
+    from: Case<'b>,
+    to: Case<'b>,
+}
+
+impl<'b, W> CaseWriter<'b, W> {
+    /// Creates a writer that converts strings into `case` and writes them to `inner`.
+    pub fn new(inner: W, case: Case<'b>) -> Self {
+        CaseWriter {
+            inner,
+            from: DEFAULT_FROM,
+            to: case,
+        }
+    }
+
+    /// Splits strings using the boundaries of `case`.
+    pub fn from_case(mut self, case: Case<'b>) -> Self {
+        self.from = case;
+        self
+    }
+
+    /// Splits strings using the given boundaries.
+    pub fn set_boundaries(mut self, boundaries: &'b [Boundary]) -> Self {
+        self.from = Case::Custom {
+            boundaries,
+            pattern: Pattern::Noop,
+            delim: "",
+        };
+        self
+    }
+
+    /// Gets a reference to the inner writer.
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the inner writer, to write to it directly.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Unwraps this `CaseWriter`, returning the inner writer.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<W: Write> CaseWriter<'_, W> {
+    /// Converts `s` and writes it to the inner writer.
+    pub fn convert(&mut self, s: &str) -> fmt::Result {
+        write_case(
+            s,
+            self.from.boundaries(),
+            self.to.pattern(),
+            self.to.delim(),
+            &mut self.inner,
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl<W: std::io::Write> CaseWriter<'_, W> {
+    /// Converts `s` and writes it to the inner writer, which is an
+    /// [`io::Write`](std::io::Write).
+    /// ```
+    /// use std::io::Write;
+    /// use convert_case::Case;
+    /// use convert_case_extras::CaseWriter;
+    ///
+    /// let mut writer = CaseWriter::new(Vec::new(), Case::Kebab);
+    /// writer.convert_io("requestId").unwrap();
+    /// writer.get_mut().write_all(b" ").unwrap();
+    /// writer.convert_io("HTTPStatus").unwrap();
+    /// assert_eq!(writer.into_inner(), b"request-id http-status");
+    /// ```
+    pub fn convert_io(&mut self, s: &str) -> std::io::Result<()> {
+        let mut adapter = IoAdapter {
+            inner: &mut self.inner,
+            error: None,
+        };
+        let result = write_case(
+            s,
+            self.from.boundaries(),
+            self.to.pattern(),
+            self.to.delim(),
+            &mut adapter,
+        );
+        match (result, adapter.error) {
+            (Ok(()), _) => Ok(()),
+            (Err(_), Some(e)) => Err(e),
+            (Err(_), None) => Err(std::io::Error::other("formatter error")),
+        }
+    }
+}
+
+/// Writes to an `io::Write` as a `fmt::Write`, keeping the error that `fmt::Error`
+/// can't carry.
+#[cfg(feature = "std")]
+struct IoAdapter<'a, W> {
+    inner: &'a mut W,
+    error: Option<std::io::Error>,
+}
+
+#[cfg(feature = "std")]
+impl<W: std::io::Write> Write for IoAdapter<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.inner.write_all(s.as_bytes()).map_err(|e| {
+            self.error = Some(e);
+            fmt::Error
+        })
+    }
+}
+
+fn write_case<W>(
+    s: &str,
+    boundaries: &[Boundary],
+    pattern: Pattern,
+    delim: &str,
+    out: &mut W,
+) -> fmt::Result
+where
+    W: Write + ?Sized,
+{
+    use Pattern::*;
+
+    let words = Words::new(s, boundaries);
+    if !matches!(
+        pattern,
+        Noop | Lowercase | Uppercase | Capital | Camel | Sentence | Toggle | Alternating
+    ) {
+        let words: Vec<&str> = words.collect();
+        for (i, word) in pattern.mutate(&words).iter().enumerate() {
+            if i > 0 {
+                out.write_str(delim)?;
+            }
+            out.write_str(word)?;
+        }
+        return Ok(());
+    }
+
+    // Whether the next letter is uppercased, which carries across words
+    let mut alternating_upper = false;
+    for (i, word) in words.enumerate() {
+        if i > 0 {
+            out.write_str(delim)?;
+        }
+        match pattern {
+            Lowercase => write_lower(word, out)?,
+            Uppercase => write_upper(word, out)?,
+            Capital => write_capital(word, out)?,
+            Camel if i == 0 => write_lower(word, out)?,
+            Camel => write_capital(word, out)?,
+            Sentence if i == 0 => write_capital(word, out)?,
+            Sentence => write_lower(word, out)?,
+            Toggle => write_toggle(word, out)?,
+            Alternating => {
+                for c in word.chars() {
+                    if c.is_uppercase() || c.is_lowercase() {
+                        if alternating_upper {
+                            write_chars(c.to_uppercase(), out)?;
+                        } else {
+                            write_chars(c.to_lowercase(), out)?;
+                        }
+                        alternating_upper = !alternating_upper;
+                    } else {
+                        out.write_char(c)?;
+                    }
+                }
+            }
+            _ => out.write_str(word)?,
+        }
+    }
+    Ok(())
+}
+
+fn write_chars<W, I>(mut chars: I, out: &mut W) -> fmt::Result
+where
+    W: Write + ?Sized,
+    I: Iterator<Item = char>,
+{
+    chars.try_for_each(|c| out.write_char(c))
+}
+
+/// Writes `word` as `str::to_lowercase` would, including the Final_Sigma rule.
+fn write_lower<W: Write + ?Sized>(word: &str, out: &mut W) -> fmt::Result {
+    for (i, c) in word.char_indices() {
+        if c == 'Σ'
+            && locale::final_sigma(word[..i].chars().rev(), word[i + c.len_utf8()..].chars())
+        {
+            out.write_char('ς')?;
+        } else {
+            write_chars(c.to_lowercase(), out)?;
+        }
+    }
+    Ok(())
+}
+
+fn write_upper<W: Write + ?Sized>(word: &str, out: &mut W) -> fmt::Result {
+    write_chars(word.chars().flat_map(char::to_uppercase), out)
+}
+
+fn write_capital<W: Write + ?Sized>(word: &str, out: &mut W) -> fmt::Result {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(c) => {
+            write_chars(c.to_uppercase(), out)?;
+            write_lower(chars.as_str(), out)
+        }
+        None => Ok(()),
+    }
+}
+
+fn write_toggle<W: Write + ?Sized>(word: &str, out: &mut W) -> fmt::Result {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(c) => {
+            write_chars(c.to_lowercase(), out)?;
+            write_upper(chars.as_str(), out)
+        }
+        None => Ok(()),
+    }
+}
+
+fn grapheme_is_digit(g: &str) -> bool {
+    g.chars().all(|c| c.is_ascii_digit())
+}
+
+fn grapheme_is_uppercase(g: &str) -> bool {
+    let upper = g.chars().flat_map(char::to_uppercase);
+    let lower = g.chars().flat_map(char::to_lowercase);
+    !upper.clone().eq(lower) && g.chars().eq(upper)
+}
+
+fn grapheme_is_lowercase(g: &str) -> bool {
+    let upper = g.chars().flat_map(char::to_uppercase);
+    let lower = g.chars().flat_map(char::to_lowercase);
+    !upper.eq(lower.clone()) && g.chars().eq(lower)
+}
+
+/// `Boundary::matches`, without allocating for the boundaries of `convert_case`.
+fn matches(boundary: Boundary, s: &[&str]) -> bool {
+    use Boundary::*;
+
+    let is = |i: usize, f: fn(&str) -> bool| s.get(i).is_some_and(|g| f(g));
+    match boundary {
+        Underscore => s.first() == Some(&"_"),
+        Hyphen => s.first() == Some(&"-"),
+        Space => s.first() == Some(&" "),
+        Acronym => {
+            is(0, grapheme_is_uppercase)
+                && is(1, grapheme_is_uppercase)
+                && is(2, grapheme_is_lowercase)
+        }
+        LowerUpper => is(0, grapheme_is_lowercase) && is(1, grapheme_is_uppercase),
+        UpperLower => is(0, grapheme_is_uppercase) && is(1, grapheme_is_lowercase),
+        LowerDigit => is(0, grapheme_is_lowercase) && is(1, grapheme_is_digit),
+        UpperDigit => is(0, grapheme_is_uppercase) && is(1, grapheme_is_digit),
+        DigitLower => is(0, grapheme_is_digit) && is(1, grapheme_is_lowercase),
+        DigitUpper => is(0, grapheme_is_digit) && is(1, grapheme_is_uppercase),
+        Custom { .. } => boundary.matches(s),
+    }
+}
+
+/// The words of a string split on boundaries, as `convert_case::split` finds them.
+///
+/// Graphemes are buffered in a fixed size window, and the boundaries of `convert_case`
+/// are given at most [`LOOKAHEAD`] graphemes instead of the rest of the string.  A
+/// [`Boundary::Custom`] condition can look at any number of graphemes, so when there
+/// is one, every grapheme is collected up front and it is given the rest of them.
+struct Words<'s, 'b> {
+    s: &'s str,
+    boundaries: &'b [Boundary],
+    graphemes: GraphemeIndices<'s>,
+    // Every grapheme, only when a boundary is custom, and the index of the current one
+    all: Option<Vec<&'s str>>,
+    position: usize,
+    // The buffered graphemes are `buf[start..end]`, and `indices` are their byte indices
+    buf: [&'s str; 2 * LOOKAHEAD],
+    indices: [usize; 2 * LOOKAHEAD],
+    start: usize,
+    end: usize,
+    // Where the next word starts
+    word_start: usize,
+    done: bool,
+}
+
+impl<'s, 'b> Words<'s, 'b> {
+    fn new(s: &'s str, boundaries: &'b [Boundary]) -> Self {
+        Words {
+            s,
+            boundaries,
+            graphemes: s.grapheme_indices(true),
+            all: boundaries
+                .iter()
+                .any(|boundary| matches!(boundary, Boundary::Custom { .. }))
+                .then(|| s.graphemes(true).collect()),
+            position: 0,
+            buf: [""; 2 * LOOKAHEAD],
+            indices: [0; 2 * LOOKAHEAD],
+            start: 0,
+            end: 0,
+            word_start: 0,
+            done: s.is_empty(),
+        }
+    }
+
+    /// Buffers graphemes until the window is full or the string is exhausted.
+    fn fill(&mut self) {
+        while self.end - self.start < LOOKAHEAD {
+            if self.end == self.buf.len() {
+                self.buf.copy_within(self.start..self.end, 0);
+                self.indices.copy_within(self.start..self.end, 0);
+                self.end -= self.start;
+                self.start = 0;
+            }
+            match self.graphemes.next() {
+                Some((i, g)) => {
+                    self.buf[self.end] = g;
+                    self.indices[self.end] = i;
+                    self.end += 1;
+                }
+                None => break,
+            }
+        }
+    }
+
+    /// The byte index of the grapheme `n` graphemes after the current one.
+    fn offset(&self, n: usize) -> usize {
+        match self.indices[self.start..self.end].get(n) {
+            Some(&i) => i,
+            None => {
+                let here = self.indices[self.start];
+                self.s[here..]
+                    .grapheme_indices(true)
+                    .nth(n)
+                    .map_or(self.s.len(), |(i, _)| here + i)
+            }
+        }
+    }
+}
+
+impl<'s> Iterator for Words<'s, '_> {
+    type Item = &'s str;
+
+    fn next(&mut self) -> Option<&'s str> {
+        loop {
+            if self.done {
+                return None;
+            }
+            self.fill();
+            if self.start == self.end {
+                self.done = true;
+                return Some(&self.s[self.word_start..]);
+            }
+
+            let window = &self.buf[self.start..self.end];
+            let rest = match &self.all {
+                Some(all) => &all[self.position..],
+                None => window,
+            };
+            let found = self.boundaries.iter().copied().find(|&boundary| {
+                if let Boundary::Custom { .. } = boundary {
+                    boundary.matches(rest)
+                } else {
+                    matches(boundary, window)
+                }
+            });
+            let word = found.map(|boundary| {
+                let from = self.offset(boundary.start());
+                let to = self.offset(boundary.start() + boundary.len());
+                let word = &self.s[self.word_start.min(from)..from];
+                self.word_start = to;
+                word
+            });
+            self.start += 1;
+            self.position += 1;
+            if word.is_some() {
+                return word;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use convert_case::Casing;
+
+    use crate::case;
+
+    const INPUTS: &[&str] = &[
+        "",
+        "a",
+        "myVarName",
+        "XMLHttpRequest",
+        "user_account_ID2",
+        "__leading--and  doubled__",
+        "Dvořák caféBar",
+        "ΟΔΟΣ ΟΔΟΣ_ΣΑΣ",
+        "e\u{301}cole Ecole\u{301}",
+        "E1M1 Hangar",
+        "a.b.c",
+    ];
+
+    fn convert(s: &str, case: Case) -> String {
+        let mut out = String::new();
+        convert_into(s, case, &mut out).unwrap();
+        out
+    }
+
+    #[test]
+    fn matches_to_case() {
+        for &case in Case::deterministic_cases().iter() {
+            for s in INPUTS {
+                assert_eq!(convert(s, case), s.to_case(case), "{:?} {:?}", s, case);
+            }
+        }
+    }
+
+    #[test]
+    fn custom_patterns() {
+        for case in [case::TOGGLE, case::TITLE_CHICAGO, case::DOT] {
+            for s in INPUTS {
+                assert_eq!(convert(s, case), s.to_case(case));
+            }
+        }
+    }
+
+    #[test]
+    fn custom_boundaries() {
+        let long = [Boundary::from_delim("::")];
+        for s in ["a::b::c", "a::", "::", "x"] {
+            let mut writer = CaseWriter::new(String::new(), Case::Pascal).set_boundaries(&long);
+            writer.convert(s).unwrap();
+            assert_eq!(
+                writer.into_inner(),
+                s.with_boundaries(&long).to_case(Case::Pascal)
+            );
+        }
+
+        // Longer than the window of graphemes given to conditions
+        let s = "word".repeat(10);
+        let mut writer = CaseWriter::new(String::new(), Case::Snake).from_case(Case::Kebab);
+        writer.convert(&s).unwrap();
+        assert_eq!(writer.get_ref(), &s);
+    }
+
+    #[test]
+    fn custom_conditions_see_rest_of_string() {
+        // The run of acronyms is longer than the window of the boundaries of convert_case
+        let boundaries = crate::boundary::defaults_with_acronyms(crate::boundary::INITIALISMS);
+        let s = "getHTTPSURLAPIUUIDJSONXMLHTTPSURLAPIIDs";
+        let mut writer = CaseWriter::new(String::new(), Case::Snake).set_boundaries(&boundaries);
+        writer.convert(s).unwrap();
+        assert_eq!(
+            writer.into_inner(),
+            s.with_boundaries(&boundaries).to_case(Case::Snake)
+        );
+        assert_eq!(
+            s.with_boundaries(&boundaries).to_case(Case::Snake),
+            "get_https_url_api_uuid_json_xml_https_url_api_ids"
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn io_errors() {
+        struct Full;
+
+        impl std::io::Write for Full {
+            fn write(&mut self, _: &[u8]) -> std::io::Result<usize> {
+                Err(std::io::ErrorKind::WriteZero.into())
+            }
+
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let mut writer = CaseWriter::new(Full, Case::Snake);
+        let err = writer.convert_io("myVar").unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::WriteZero);
+    }
+}